@@ -0,0 +1,268 @@
+use super::{
+    DifferentiableKernel, KernelBase, OrderedField, Promote, Real, TanhKernel, UnaryKernel,
+};
+
+// Activation functions with their derivative rules, e.g. `x.map(SiluKernel)`.
+// Unlike the plain `Real` kernels these do not promote: storage types widen first.
+
+// 1 / (1 + e^-x) through e^(-|x|), which never overflows
+fn sigmoid<F: Real>(x: F) -> F {
+    let e = (-x.abs()).exp();
+    if x >= F::zero() {
+        (F::one() + e).recip()
+    } else {
+        e / (F::one() + e)
+    }
+}
+
+macro_rules! activation {
+    ($(#[$doc:meta])* $name:ident, |$x:ident| $f:expr, |$dx:ident| $df:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl<F: Real> UnaryKernel<F> for $name {
+            type Output = F;
+
+            #[inline(always)]
+            fn apply(&self, $x: F) -> F {
+                $f
+            }
+        }
+
+        impl<F: Real> DifferentiableKernel<F> for $name {
+            #[inline(always)]
+            fn derivative(&self, $dx: F) -> F {
+                $df
+            }
+        }
+    };
+}
+
+activation!(
+    // max(x, 0); the derivative at 0 is taken as 0.
+    ReluKernel,
+    |x| OrderedField::max(x, F::zero()),
+    |x| if x > F::zero() { F::one() } else { F::zero() }
+);
+
+activation!(SigmoidKernel, |x| sigmoid(x), |x| {
+    let s = sigmoid(x);
+    s * (F::one() - s)
+});
+
+activation!(
+    // ln(1 + e^x) = max(x, 0) + ln(1 + e^-|x|), exact in both tails
+    SoftplusKernel,
+    |x| OrderedField::max(x, F::zero()) + (-x.abs()).exp().log1p(),
+    |x| sigmoid(x)
+);
+
+activation!(
+    // Exact GELU, x Φ(x), rather than the tanh approximation
+    GeluKernel,
+    |x| x * x.norm_cdf(),
+    |x| x.norm_cdf() + x * x.norm_pdf()
+);
+
+activation!(
+    // x σ(x), a.k.a. swish
+    SiluKernel,
+    |x| x * sigmoid(x),
+    |x| {
+        let s = sigmoid(x);
+        s * (F::one() + x * (F::one() - s))
+    }
+);
+
+// Tanh itself is the plain `Real` kernel; only its derivative lives here.
+impl<F: Real + Promote<F, Output = F>> DifferentiableKernel<F> for TanhKernel {
+    #[inline(always)]
+    fn derivative(&self, x: F) -> F {
+        let t = x.tanh();
+        F::one() - t * t
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LeakyReluKernel<F> {
+    pub slope: F,
+}
+
+impl<F: Real> UnaryKernel<F> for LeakyReluKernel<F> {
+    type Output = F;
+
+    #[inline(always)]
+    fn apply(&self, x: F) -> F {
+        if x > F::zero() { x } else { self.slope * x }
+    }
+}
+
+impl<F: Real> DifferentiableKernel<F> for LeakyReluKernel<F> {
+    #[inline(always)]
+    fn derivative(&self, x: F) -> F {
+        if x > F::zero() { F::one() } else { self.slope }
+    }
+}
+
+// Stand-in for the derivative of the unit step H(x), which is zero almost everywhere and
+// would stop all gradient flow. Used by `HardStepKernel` and `SignKernel` (= 2H - 1).
+pub trait Surrogate<F>: KernelBase {
+    fn step_derivative(&self, x: F) -> F;
+}
+
+/// Straight-through estimator: the derivative of the hard sigmoid `clamp((x + 1) / 2, 0, 1)`.
+/// `SignKernel` then passes gradients unchanged on [-1, 1], the usual hardtanh STE.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StraightThrough;
+
+impl<F: Real> Surrogate<F> for StraightThrough {
+    #[inline(always)]
+    fn step_derivative(&self, x: F) -> F {
+        if x.abs() <= F::one() {
+            (F::one() + F::one()).recip()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+/// Sigmoid-STE: the derivative of σ(βx). Larger `beta` is closer to the true step
+/// but passes gradient through a narrower band around 0.
+#[derive(Debug, Clone, Copy)]
+pub struct SigmoidSurrogate<F> {
+    pub beta: F,
+}
+
+impl<F: Real> Surrogate<F> for SigmoidSurrogate<F> {
+    #[inline(always)]
+    fn step_derivative(&self, x: F) -> F {
+        let s = sigmoid(self.beta * x);
+        self.beta * s * (F::one() - s)
+    }
+}
+
+/// McCulloch–Pitts unit: 1 for x > 0, else 0. Backpropagates through `surrogate`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HardStepKernel<S = StraightThrough> {
+    pub surrogate: S,
+}
+
+impl HardStepKernel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: Real, S: Surrogate<F>> UnaryKernel<F> for HardStepKernel<S> {
+    type Output = F;
+
+    #[inline(always)]
+    fn apply(&self, x: F) -> F {
+        if x > F::zero() { F::one() } else { F::zero() }
+    }
+}
+
+impl<F: Real, S: Surrogate<F>> DifferentiableKernel<F> for HardStepKernel<S> {
+    #[inline(always)]
+    fn derivative(&self, x: F) -> F {
+        self.surrogate.step_derivative(x)
+    }
+}
+
+/// -1, 0 or 1. Backpropagates through `surrogate`, scaled by the jump of 2.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SignKernel<S = StraightThrough> {
+    pub surrogate: S,
+}
+
+impl SignKernel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: Real, S: Surrogate<F>> UnaryKernel<F> for SignKernel<S> {
+    type Output = F;
+
+    #[inline(always)]
+    fn apply(&self, x: F) -> F {
+        x.signum()
+    }
+}
+
+impl<F: Real, S: Surrogate<F>> DifferentiableKernel<F> for SignKernel<S> {
+    #[inline(always)]
+    fn derivative(&self, x: F) -> F {
+        let d = self.surrogate.step_derivative(x);
+        d + d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tolerance, TradingFloat, assert_approx_eq};
+
+    // Central difference against the analytic rule
+    fn check<K: DifferentiableKernel<TradingFloat>>(kernel: K, xs: &[f64]) {
+        let h = 1e-6;
+        for &x in xs {
+            let (lo, hi) = (
+                kernel.apply(TradingFloat::new(x - h)),
+                kernel.apply(TradingFloat::new(x + h)),
+            );
+            let numeric = (hi - lo) / TradingFloat::new(2.0 * h);
+            assert_approx_eq!(
+                kernel.derivative(TradingFloat::new(x)),
+                numeric,
+                Tolerance::Absolute(1e-8)
+            );
+        }
+    }
+
+    #[test]
+    fn test_activation_derivatives() {
+        let xs = [-3.0, -0.4, 0.3, 2.5];
+        check(ReluKernel, &xs);
+        check(
+            LeakyReluKernel {
+                slope: TradingFloat::new(0.01),
+            },
+            &xs,
+        );
+        check(SigmoidKernel, &xs);
+        check(TanhKernel, &xs);
+        check(SoftplusKernel, &xs);
+        check(GeluKernel, &xs);
+        check(SiluKernel, &xs);
+
+        let tf = TradingFloat::new;
+        // No overflow in the tails
+        assert_eq!(SigmoidKernel.apply(tf(-800.0)), tf(0.0));
+        assert_eq!(SoftplusKernel.apply(tf(800.0)), tf(800.0));
+        assert_approx_eq!(SoftplusKernel.apply(tf(-40.0)), tf(-40.0).exp());
+
+        // Steps are flat almost everywhere; the surrogate decides the gradient.
+        let step = HardStepKernel::new();
+        assert_eq!(
+            (step.apply(tf(0.0)), step.apply(tf(1e-9))),
+            (tf(0.0), tf(1.0))
+        );
+        assert_eq!(step.derivative(tf(0.2)), tf(0.5));
+        assert_eq!(step.derivative(tf(-1.5)), tf(0.0));
+        let sign = SignKernel::new();
+        assert_eq!(sign.apply(tf(-0.3)), tf(-1.0));
+        assert_eq!(sign.derivative(tf(0.9)), tf(1.0));
+
+        let smooth = SignKernel {
+            surrogate: SigmoidSurrogate { beta: tf(4.0) },
+        };
+        // d/dx (2σ(βx) - 1) = β/2 at 0, i.e. the slope of tanh(βx/2)
+        assert_eq!(smooth.derivative(tf(0.0)), tf(2.0));
+        assert_approx_eq!(
+            smooth.derivative(tf(0.7)),
+            tf(2.0) * (tf(1.0) - tf(1.4).tanh() * tf(1.4).tanh())
+        );
+    }
+}
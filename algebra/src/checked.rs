@@ -0,0 +1,238 @@
+use super::{
+    ApproxEq, ArithmeticError, CheckedArith, Field, One, Promote, Ring, Semiring, TradingFloat,
+    Zero,
+};
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Checked is a scalar that carries either a value or the first arithmetic error hit
+/// while computing it. Errors are sticky: once an operand failed, every result built
+/// from it fails with the same error, so a whole tensor expression can be evaluated
+/// by the ordinary kernels and inspected afterwards.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Checked<F>(Result<F, ArithmeticError>);
+
+impl<F> Checked<F> {
+    pub fn new(value: F) -> Self {
+        Self(Ok(value))
+    }
+
+    pub fn error(err: ArithmeticError) -> Self {
+        Self(Err(err))
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    pub fn into_result(self) -> Result<F, ArithmeticError> {
+        self.0
+    }
+}
+
+impl<F> From<F> for Checked<F> {
+    fn from(value: F) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<F> From<Checked<F>> for Result<F, ArithmeticError> {
+    fn from(value: Checked<F>) -> Self {
+        value.0
+    }
+}
+
+// Left error wins, so the reported error is the first one in evaluation order.
+#[inline]
+fn lift2<F>(
+    lhs: Checked<F>,
+    rhs: Checked<F>,
+    op: impl FnOnce(F, F) -> Result<F, ArithmeticError>,
+) -> Checked<F> {
+    Checked(lhs.0.and_then(|l| rhs.0.and_then(|r| op(l, r))))
+}
+
+impl<F: CheckedArith> Add for Checked<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        lift2(self, rhs, F::checked_add)
+    }
+}
+
+impl<F: CheckedArith> Sub for Checked<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        lift2(self, rhs, F::checked_sub)
+    }
+}
+
+impl<F: CheckedArith> Mul for Checked<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        lift2(self, rhs, F::checked_mul)
+    }
+}
+
+impl<F: CheckedArith> Div for Checked<F> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        lift2(self, rhs, F::checked_div)
+    }
+}
+
+impl<F: CheckedArith> Neg for Checked<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Checked(self.0.and_then(F::checked_neg))
+    }
+}
+
+impl<F: CheckedArith + Zero> Sum for Checked<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<F: CheckedArith + One> Product for Checked<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<F: Zero> Zero for Checked<F> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(F::zero())
+    }
+}
+
+impl<F: One> One for Checked<F> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(F::one())
+    }
+}
+
+impl<F: Semiring + CheckedArith> Semiring for Checked<F> {}
+impl<F: Ring + CheckedArith> Ring for Checked<F> {}
+impl<F: Field + CheckedArith> Field for Checked<F> {
+    #[inline]
+    fn recip(self) -> Self {
+        Checked(self.0.and_then(F::checked_recip))
+    }
+}
+
+impl<F: Copy> Checked<F> {
+    // Two failures are close when they failed the same way.
+    fn close_by(&self, other: &Self, close: impl FnOnce(&F, &F) -> bool) -> bool {
+        match (self.0, other.0) {
+            (Ok(a), Ok(b)) => close(&a, &b),
+            (Err(a), Err(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<F: ApproxEq + Copy> ApproxEq for Checked<F> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.close_by(other, |a, b| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.close_by(other, |a, b| a.relative_eq(b, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.close_by(other, |a, b| a.ulps_eq(b, max_ulps))
+    }
+}
+
+impl<F: Semiring + CheckedArith> Promote<Checked<F>> for Checked<F> {
+    type Output = Checked<F>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Checked<F>) -> Self::Output {
+        rhs
+    }
+}
+
+impl Promote<TradingFloat> for Checked<TradingFloat> {
+    type Output = Checked<TradingFloat>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: TradingFloat) -> Self::Output {
+        Checked::new(rhs)
+    }
+}
+
+impl Promote<Checked<TradingFloat>> for TradingFloat {
+    type Output = Checked<TradingFloat>;
+
+    fn promote_left(self) -> Self::Output {
+        Checked::new(self)
+    }
+
+    fn promote_right(rhs: Checked<TradingFloat>) -> Self::Output {
+        rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ArithmeticError::{DivisionByZero, Domain, Overflow};
+
+    #[test]
+    fn test_checked_failures_are_values() {
+        let t = |x: f64| Checked::new(TradingFloat::new(x));
+        assert_eq!((t(f64::MAX) * t(2.0)).into_result(), Err(Overflow));
+        assert_eq!((t(-f64::MAX) - t(f64::MAX)).into_result(), Err(Overflow));
+        assert_eq!((t(1.0) / t(0.0)).into_result(), Err(DivisionByZero));
+        assert_eq!(t(0.0).recip().into_result(), Err(DivisionByZero));
+        assert_eq!(
+            (t(f64::MAX) + t(-f64::MAX)).into_result(),
+            Ok(TradingFloat::ZERO)
+        );
+
+        // Integers report the overflow instead of panicking or wrapping.
+        let i = Checked::<i64>::new;
+        assert_eq!((i(i64::MAX) + i(1)).into_result(), Err(Overflow));
+        assert_eq!((-i(i64::MIN)).into_result(), Err(Overflow));
+        assert_eq!((i(i64::MIN) / i(-1)).into_result(), Err(Overflow));
+        assert_eq!((i(7) / i(0)).into_result(), Err(DivisionByZero));
+
+        // Raw floats: NaN is a domain error, ±Inf an overflow.
+        let f = Checked::<f64>::new;
+        assert_eq!(
+            (f(f64::INFINITY) - f(f64::INFINITY)).into_result(),
+            Err(Domain)
+        );
+        assert_eq!((f(0.0) * f(f64::INFINITY)).into_result(), Err(Domain));
+        assert_eq!((f(1e308) * f(10.0)).into_result(), Err(Overflow));
+    }
+
+    #[test]
+    fn test_checked_errors_are_sticky() {
+        let t = |x: f64| Checked::new(TradingFloat::new(x));
+        let (div, overflow) = (t(1.0) / t(0.0), t(f64::MAX) * t(2.0));
+        assert_eq!((div + overflow).into_result(), Err(DivisionByZero));
+        assert_eq!((overflow * div).into_result(), Err(Overflow));
+        assert_eq!((-div).into_result(), Err(DivisionByZero));
+
+        let sum = [t(1.0), div, overflow].into_iter().sum::<Checked<_>>();
+        assert_eq!(sum.into_result(), Err(DivisionByZero));
+        let product = [t(2.0), t(3.0)].into_iter().product::<Checked<_>>();
+        assert_eq!(product.into_result(), Ok(TradingFloat::new(6.0)));
+
+        // Failures are close only to failures of the same kind.
+        assert!(div.abs_diff_eq(&(t(2.0) / t(0.0)), 0.0));
+        assert!(!div.abs_diff_eq(&overflow, f64::MAX));
+        assert!(!div.abs_diff_eq(&t(1.0), f64::MAX));
+    }
+}
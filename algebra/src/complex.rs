@@ -0,0 +1,291 @@
+use super::{ApproxEq, Field, One, OrderedField, Promote, Real, Ring, Semiring, Zero};
+use core::fmt::{self, Display};
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Complex is a Cartesian complex number over a real scalar. Multi-valued functions
+/// (`arg`, `ln`, `sqrt`, `powf`, `powc`) return the principal branch, with the
+/// argument in (-π, π] and the branch cut along the negative real axis.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+impl<F> Complex<F> {
+    pub const fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<F: Real> Complex<F> {
+    pub fn i() -> Self {
+        Self::new(F::zero(), F::one())
+    }
+
+    pub fn from_polar(r: F, theta: F) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn norm_sqr(self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    // Scaled hypot so |z| does not overflow when |z|² would
+    pub fn norm(self) -> F {
+        let (a, b) = (self.re.abs(), self.im.abs());
+        let (hi, lo) = (OrderedField::max(a, b), OrderedField::min(a, b));
+        if hi == F::zero() {
+            return hi;
+        }
+        let r = lo / hi;
+        hi * (F::one() + r * r).sqrt()
+    }
+
+    pub fn arg(self) -> F {
+        self.im.atan2(self.re)
+    }
+
+    pub fn to_polar(self) -> (F, F) {
+        (self.norm(), self.arg())
+    }
+
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    pub fn ln(self) -> Self {
+        Self::new(self.norm().ln(), self.arg())
+    }
+
+    pub fn sqrt(self) -> Self {
+        if self == Self::zero() {
+            return self;
+        }
+        // Kahan's formulation: the root is taken of a sum of non-negative terms,
+        // so neither component suffers cancellation.
+        let two = F::one() + F::one();
+        let t = ((self.re.abs() + self.norm()) / two).sqrt();
+        if self.re >= F::zero() {
+            Self::new(t, self.im / (two * t))
+        } else if self.im < F::zero() {
+            Self::new(self.im.abs() / (two * t), -t)
+        } else {
+            Self::new(self.im.abs() / (two * t), t)
+        }
+    }
+
+    pub fn powf(self, exp: F) -> Self {
+        if self == Self::zero() {
+            return self;
+        }
+        let (r, theta) = self.to_polar();
+        Self::from_polar(r.pow(exp), theta * exp)
+    }
+
+    pub fn powc(self, exp: Self) -> Self {
+        if self == Self::zero() {
+            return self;
+        }
+        (self.ln() * exp).exp()
+    }
+}
+
+impl<F> From<F> for Complex<F>
+where
+    F: Zero,
+{
+    fn from(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+}
+
+impl<F: Display + Real> Display for Complex<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < F::zero() {
+            write!(f, "{}-{}i", self.re, self.im.abs())
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<F: Real> Add for Complex<F> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<F: Real> Sub for Complex<F> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<F: Real> Mul for Complex<F> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<F: Real> Div for Complex<F> {
+    type Output = Self;
+    // Smith's algorithm: divides through by the larger component of the divisor
+    // so the intermediate |rhs|² never overflows.
+    fn div(self, rhs: Self) -> Self {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        if c.abs() >= d.abs() {
+            let r = d / c;
+            let den = c + d * r;
+            Self::new((a + b * r) / den, (b - a * r) / den)
+        } else {
+            let r = c / d;
+            let den = c * r + d;
+            Self::new((a * r + b) / den, (b * r - a) / den)
+        }
+    }
+}
+
+impl<F: Real> Neg for Complex<F> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<F: Real> Sum for Complex<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<F: Real> Product for Complex<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<F: Zero> Zero for Complex<F> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+}
+
+impl<F: Zero + One> One for Complex<F> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Real> Semiring for Complex<F> {}
+impl<F: Real> Ring for Complex<F> {}
+impl<F: Real> Field for Complex<F> {
+    #[inline]
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+}
+
+// Componentwise: both parts must be within tolerance
+impl<F: ApproxEq> ApproxEq for Complex<F> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon) && self.im.abs_diff_eq(&other.im, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.re.relative_eq(&other.re, max_relative) && self.im.relative_eq(&other.im, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.re.ulps_eq(&other.re, max_ulps) && self.im.ulps_eq(&other.im, max_ulps)
+    }
+}
+
+impl<F: Real> Promote<Complex<F>> for Complex<F> {
+    type Output = Complex<F>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Complex<F>) -> Self::Output {
+        rhs
+    }
+}
+
+impl<F: Real> Promote<F> for Complex<F> {
+    type Output = Complex<F>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: F) -> Self::Output {
+        Complex::from(rhs)
+    }
+}
+
+impl<F: Real> Promote<Complex<F>> for F {
+    type Output = Complex<F>;
+
+    fn promote_left(self) -> Self::Output {
+        Complex::from(self)
+    }
+
+    fn promote_right(rhs: Complex<F>) -> Self::Output {
+        rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tolerance, TradingFloat, assert_approx_eq};
+
+    type C = Complex<TradingFloat>;
+
+    fn c(re: f64, im: f64) -> C {
+        Complex::new(TradingFloat::new(re), TradingFloat::new(im))
+    }
+
+    #[test]
+    fn test_complex_principal_branch() {
+        let tol = Tolerance::Absolute(1e-12);
+        let pi = TradingFloat::pi();
+
+        // Euler's identity
+        assert_approx_eq!((C::i() * Complex::from(pi)).exp(), c(-1.0, 0.0), tol);
+        assert_approx_eq!(c(-1.0, 0.0).ln(), c(0.0, pi.into()), tol);
+        assert_approx_eq!(c(-4.0, 0.0).sqrt(), c(0.0, 2.0), tol);
+        assert_approx_eq!(c(0.0, -2.0).sqrt(), c(1.0, -1.0), tol);
+        assert_approx_eq!(c(3.0, 4.0).norm(), TradingFloat::new(5.0), tol);
+        assert_approx_eq!(
+            c(1e300, 1e300).norm(),
+            TradingFloat::new(2f64.sqrt() * 1e300)
+        );
+
+        let z = c(1.5, -2.0);
+        assert_approx_eq!(z / z, C::one(), tol);
+        assert_approx_eq!(z * z.recip(), C::one(), tol);
+        assert_approx_eq!(z.sqrt() * z.sqrt(), z, tol);
+        assert_approx_eq!(z.powc(c(2.0, 0.0)), z * z, tol);
+        assert_eq!(z * z.conj(), Complex::from(z.norm_sqr()));
+        assert_eq!(z.to_string(), "1.5-2i");
+    }
+}
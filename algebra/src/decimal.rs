@@ -0,0 +1,569 @@
+use super::{
+    ApproxEq, ArithmeticError, CheckedArith, Discretization, Field, One, OrderedField, Promote,
+    Ring, Semiring, TradingFloat, Zero,
+};
+use core::fmt::{self, Display};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Decimal is a fixed-point scalar worth `mantissa * 10^-SCALE`.
+/// Prices and quantities on a tick grid stay exact through the same kernels as `TradingFloat`.
+/// Invariants:
+/// - Addition, subtraction, negation and remainder are exact
+/// - Multiplication and division round half to even at the last decimal place
+/// - Intermediate products are 256-bit, only the final result must fit the i128 mantissa
+/// - Overflow and division by zero panic in every build (a wrapped price is never acceptable);
+///   use the `checked_*` methods or `Checked<Decimal<SCALE>>` to handle them as values
+/// - `SCALE <= 38`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const SCALE: u32>(i128);
+
+impl<const SCALE: u32> Decimal<SCALE> {
+    pub const FACTOR: i128 = {
+        assert!(SCALE <= 38, "Decimal SCALE must be at most 38");
+        10i128.pow(SCALE)
+    };
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(Self::FACTOR);
+    pub const MAX: Self = Self(i128::MAX);
+    pub const MIN: Self = Self(i128::MIN);
+
+    /// Raw constructor: `Decimal::<2>::from_mantissa(12345)` is `123.45`.
+    pub const fn from_mantissa(mantissa: i128) -> Self {
+        Self(mantissa)
+    }
+
+    pub fn from_int(value: i64) -> Self {
+        Self::from_i128(value as i128)
+    }
+
+    /// Whole units of any integer width; panics if `value * 10^SCALE` overflows.
+    pub fn from_i128(value: i128) -> Self {
+        Self(value.checked_mul(Self::FACTOR).expect("Decimal overflow"))
+    }
+
+    pub const fn mantissa(self) -> i128 {
+        self.0
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::FACTOR as f64
+    }
+
+    pub fn to_trading_float(self) -> TradingFloat {
+        TradingFloat::new(self.to_f64())
+    }
+
+    /// Moves to another scale, rounding half to even when precision is dropped.
+    pub fn rescale<const TO: u32>(self) -> Result<Decimal<TO>, ArithmeticError> {
+        mul_div(self.0, Decimal::<TO>::FACTOR, Self::FACTOR).map(Decimal)
+    }
+}
+
+// round_half_even(a * b / d) with a 256-bit intermediate product.
+// Overflow if the quotient does not fit the i128 mantissa.
+fn mul_div(a: i128, b: i128, d: i128) -> Result<i128, ArithmeticError> {
+    if d == 0 {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+    let negative = (a < 0) ^ (b < 0) ^ (d < 0);
+    let (ua, ub, ud) = (a.unsigned_abs(), b.unsigned_abs(), d.unsigned_abs());
+
+    let (q, r) = match ua.checked_mul(ub) {
+        Some(p) => (p / ud, p % ud),
+        None => {
+            let (hi, lo) = widening_mul(ua, ub);
+            div_wide(hi, lo, ud).ok_or(ArithmeticError::Overflow)?
+        }
+    };
+
+    // r < ud, so comparing against the complement avoids doubling r.
+    let half = ud - r;
+    let round_up = r > half || (r == half && q & 1 == 1);
+    let q = if round_up {
+        q.checked_add(1).ok_or(ArithmeticError::Overflow)?
+    } else {
+        q
+    };
+
+    if negative {
+        0i128
+            .checked_sub_unsigned(q)
+            .ok_or(ArithmeticError::Overflow)
+    } else {
+        i128::try_from(q).map_err(|_| ArithmeticError::Overflow)
+    }
+}
+
+// 128 x 128 -> 256 bit product as (hi, lo).
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+// (hi, lo) / d by restoring long division. None if the quotient needs more than 128 bits.
+fn div_wide(hi: u128, lo: u128, d: u128) -> Option<(u128, u128)> {
+    if hi >= d {
+        return None;
+    }
+    let mut r = hi;
+    let mut q = 0u128;
+    for i in (0..128).rev() {
+        let carry = r >> 127;
+        r = (r << 1) | ((lo >> i) & 1);
+        if carry == 1 || r >= d {
+            r = r.wrapping_sub(d);
+            q |= 1 << i;
+        }
+    }
+    Some((q, r))
+}
+
+impl<const SCALE: u32> Display for Decimal<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        let factor = Self::FACTOR as u128;
+        if SCALE == 0 {
+            write!(f, "{}{}", sign, abs)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$}",
+                sign,
+                abs / factor,
+                abs % factor,
+                width = SCALE as usize
+            )
+        }
+    }
+}
+
+impl<const SCALE: u32> FromStr for Decimal<SCALE> {
+    type Err = &'static str;
+
+    /// Parses `[-+]digits[.digits]`; extra fractional digits round half to even.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, body) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int_part, frac_part) = body.split_once('.').unwrap_or((body, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err("Empty Decimal literal");
+        }
+
+        let mut digits: i128 = 0;
+        for c in int_part.bytes().chain(frac_part.bytes()) {
+            if !c.is_ascii_digit() {
+                return Err("Invalid digit in Decimal literal");
+            }
+            digits = digits
+                .checked_mul(10)
+                .and_then(|d| d.checked_add((c - b'0') as i128))
+                .ok_or("Decimal literal out of range")?;
+        }
+        if negative {
+            digits = -digits;
+        }
+
+        let frac_len = frac_part.len() as u32;
+        let mantissa = if frac_len <= SCALE {
+            10i128
+                .checked_pow(SCALE - frac_len)
+                .and_then(|scale| digits.checked_mul(scale))
+        } else {
+            10i128
+                .checked_pow(frac_len - SCALE)
+                .and_then(|scale| mul_div(digits, 1, scale).ok())
+        };
+        mantissa.map(Self).ok_or("Decimal literal out of range")
+    }
+}
+
+impl<const SCALE: u32> TryFrom<TradingFloat> for Decimal<SCALE> {
+    type Error = &'static str;
+
+    /// Rounds half to even onto the tick grid.
+    fn try_from(value: TradingFloat) -> Result<Self, Self::Error> {
+        let scaled = (value.to_f64() * Self::FACTOR as f64).round_ties_even();
+        // i128::MAX as f64 rounds up to 2^127, which itself is out of range.
+        if scaled >= -(i128::MIN as f64) || scaled < i128::MIN as f64 {
+            Err("Value out of Decimal range")
+        } else {
+            Ok(Self(scaled as i128))
+        }
+    }
+}
+
+impl<const SCALE: u32> From<Decimal<SCALE>> for TradingFloat {
+    fn from(value: Decimal<SCALE>) -> Self {
+        value.to_trading_float()
+    }
+}
+
+impl<const SCALE: u32> CheckedArith for Decimal<SCALE> {
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or(ArithmeticError::Overflow)
+    }
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or(ArithmeticError::Overflow)
+    }
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        mul_div(self.0, rhs.0, Self::FACTOR).map(Self)
+    }
+    #[inline]
+    fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        mul_div(self.0, Self::FACTOR, rhs.0).map(Self)
+    }
+    #[inline]
+    fn checked_rem(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.0
+            .checked_rem(rhs.0)
+            .map(Self)
+            .ok_or(ArithmeticError::Overflow)
+    }
+    #[inline]
+    fn checked_neg(self) -> Result<Self, ArithmeticError> {
+        self.0
+            .checked_neg()
+            .map(Self)
+            .ok_or(ArithmeticError::Overflow)
+    }
+    #[inline]
+    fn checked_recip(self) -> Result<Self, ArithmeticError> {
+        Self::ONE.checked_div(self)
+    }
+}
+
+fn expect_exact<const SCALE: u32>(
+    result: Result<Decimal<SCALE>, ArithmeticError>,
+    op: &str,
+    lhs: Decimal<SCALE>,
+    rhs: Decimal<SCALE>,
+) -> Decimal<SCALE> {
+    result.unwrap_or_else(|err| panic!("Decimal {}: {} {} {}", err, lhs, op, rhs))
+}
+
+impl<const SCALE: u32> Add for Decimal<SCALE> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        expect_exact(self.checked_add(rhs), "+", self, rhs)
+    }
+}
+
+impl<const SCALE: u32> Sub for Decimal<SCALE> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        expect_exact(self.checked_sub(rhs), "-", self, rhs)
+    }
+}
+
+impl<const SCALE: u32> Mul for Decimal<SCALE> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        expect_exact(self.checked_mul(rhs), "*", self, rhs)
+    }
+}
+
+impl<const SCALE: u32> Div for Decimal<SCALE> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        expect_exact(self.checked_div(rhs), "/", self, rhs)
+    }
+}
+
+impl<const SCALE: u32> Rem for Decimal<SCALE> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        expect_exact(self.checked_rem(rhs), "%", self, rhs)
+    }
+}
+
+impl<const SCALE: u32> Neg for Decimal<SCALE> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.checked_neg().expect("Decimal overflow: negating MIN")
+    }
+}
+
+impl<const SCALE: u32> AddAssign for Decimal<SCALE> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const SCALE: u32> SubAssign for Decimal<SCALE> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const SCALE: u32> MulAssign for Decimal<SCALE> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const SCALE: u32> DivAssign for Decimal<SCALE> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const SCALE: u32> RemAssign for Decimal<SCALE> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+// Integer addition is exact, so no compensation is needed.
+impl<const SCALE: u32> Sum for Decimal<SCALE> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<const SCALE: u32> Product for Decimal<SCALE> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<const SCALE: u32> Zero for Decimal<SCALE> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const SCALE: u32> One for Decimal<SCALE> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const SCALE: u32> Semiring for Decimal<SCALE> {}
+impl<const SCALE: u32> Ring for Decimal<SCALE> {}
+// Division rounds half to even at the last decimal place.
+impl<const SCALE: u32> Field for Decimal<SCALE> {
+    #[inline]
+    fn recip(self) -> Self {
+        expect_exact(self.checked_recip(), "/", Self::ONE, self)
+    }
+}
+
+impl<const SCALE: u32> OrderedField for Decimal<SCALE> {
+    #[inline]
+    fn abs(self) -> Self {
+        if self.0 < 0 { -self } else { self }
+    }
+    #[inline]
+    fn signum(self) -> Self {
+        match self.0.signum() {
+            1 => Self::ONE,
+            -1 => -Self::ONE,
+            _ => Self::ZERO,
+        }
+    }
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    #[inline]
+    fn clamp(self, lo: Self, hi: Self) -> Self {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+// Rounds to whole units; `round` goes half away from zero like `f64::round`.
+impl<const SCALE: u32> Discretization for Decimal<SCALE> {
+    #[inline]
+    fn floor(self) -> Self {
+        // Drops the fraction; below the lowest whole unit that overflows like any other op.
+        let fraction = Self(self.0.rem_euclid(Self::FACTOR));
+        expect_exact(self.checked_sub(fraction), "-", self, fraction)
+    }
+    #[inline]
+    fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+    #[inline]
+    fn round(self) -> Self {
+        let half = Self(Self::FACTOR / 2);
+        if self.0 < 0 {
+            -(-self).round()
+        } else if SCALE == 0 {
+            self
+        } else {
+            (self + half).floor()
+        }
+    }
+}
+
+// One ulp of a Decimal is one tick (one unit of the mantissa).
+impl<const SCALE: u32> ApproxEq for Decimal<SCALE> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (self.to_f64() - other.to_f64()).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.to_f64().relative_eq(&other.to_f64(), max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.0.abs_diff(other.0) <= max_ulps as u128
+    }
+}
+
+impl<const SCALE: u32> Promote<Decimal<SCALE>> for Decimal<SCALE> {
+    type Output = Decimal<SCALE>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Decimal<SCALE>) -> Self::Output {
+        rhs
+    }
+}
+
+// Mixing with floats leaves the exact world.
+impl<const SCALE: u32> Promote<TradingFloat> for Decimal<SCALE> {
+    type Output = TradingFloat;
+
+    fn promote_left(self) -> Self::Output {
+        self.to_trading_float()
+    }
+
+    fn promote_right(rhs: TradingFloat) -> Self::Output {
+        rhs
+    }
+}
+
+impl<const SCALE: u32> Promote<Decimal<SCALE>> for TradingFloat {
+    type Output = TradingFloat;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Decimal<SCALE>) -> Self::Output {
+        rhs.to_trading_float()
+    }
+}
+
+impl<const SCALE: u32> Promote<bool> for Decimal<SCALE> {
+    type Output = Decimal<SCALE>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: bool) -> Self::Output {
+        if rhs { Self::ONE } else { Self::ZERO }
+    }
+}
+
+impl<const SCALE: u32> Promote<Decimal<SCALE>> for bool {
+    type Output = Decimal<SCALE>;
+
+    fn promote_left(self) -> Self::Output {
+        if self { Decimal::ONE } else { Decimal::ZERO }
+    }
+
+    fn promote_right(rhs: Decimal<SCALE>) -> Self::Output {
+        rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Price = Decimal<4>;
+
+    fn d(s: &str) -> Price {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        assert_eq!(d("0.1") + d("0.2"), d("0.3"));
+        assert_eq!(d("101.25").to_string(), "101.2500");
+        assert_eq!(d("-0.00005").to_string(), "0.0000");
+        assert_eq!(d("0.00015").to_string(), "0.0002");
+
+        // 0.0001 * 0.5 = 0.00005 -> ties to even
+        assert_eq!(d("0.0001") * d("0.5"), d("0"));
+        assert_eq!(d("0.0003") * d("0.5"), d("0.0002"));
+        assert_eq!(d("1") / d("3"), d("0.3333"));
+        assert_eq!(d("2") / d("3"), d("0.6667"));
+        assert_eq!(d("-2") / d("3"), d("-0.6667"));
+
+        // Operands whose raw product overflows i128 but whose result fits
+        let big = Price::from_mantissa(i128::MAX / 3);
+        assert_eq!(big * Price::ONE, big);
+        assert_eq!(big.checked_mul(d("10000")), Err(ArithmeticError::Overflow));
+        assert_eq!(
+            d("1").checked_div(Price::ZERO),
+            Err(ArithmeticError::DivisionByZero)
+        );
+
+        assert_eq!(d("-1.5").floor(), d("-2"));
+        assert_eq!(d("-1.5").ceil(), d("-1"));
+        assert_eq!(d("-1.5").round(), d("-2"));
+        assert_eq!(d("2.5").round(), d("3"));
+
+        // The lowest whole unit floors to itself; anything below it has no floor.
+        let lowest = Price::from_mantissa(i128::MIN / Price::FACTOR * Price::FACTOR);
+        assert_eq!(lowest.floor(), lowest);
+        assert_eq!(Price::from_mantissa(lowest.mantissa() + 1).floor(), lowest);
+        assert!(std::panic::catch_unwind(|| Price::MIN.floor()).is_err());
+        assert!(std::panic::catch_unwind(|| Price::MIN.round()).is_err());
+
+        let fee: Decimal<2> = d("12.3456").rescale().unwrap();
+        assert_eq!(fee.to_string(), "12.35");
+        assert_eq!(
+            Price::try_from(TradingFloat::new(0.1 + 0.2)).unwrap(),
+            d("0.3")
+        );
+    }
+}
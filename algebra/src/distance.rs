@@ -0,0 +1,239 @@
+use super::{
+    AbsKernel, BinaryKernel, ChainedKernel, Line, MaxKernel, MeanKernel, MulKernel, PowiKernel,
+    Promote, Real, ReduceKernel, SubKernel, SumKernel,
+};
+use core::fmt::Debug;
+use std::sync::Arc;
+
+/// Distance between two feature vectors of equal length, as used by `cdist`.
+/// Not `Copy` like the kernels: a metric may own parameters (e.g. `Mahalanobis`).
+///
+/// Rows are `prepare`d once, then `distance` compares prepared rows. Per-row work such as
+/// demeaning or whitening therefore runs O(n + m) times in `cdist` rather than per pair,
+/// and `distance` does not allocate.
+pub trait DistanceMetric<F>: Clone + Debug + Send + Sync + 'static {
+    // Rewrites a row in place into the form `distance` expects; the identity by default.
+    fn prepare(&self, _row: &mut [F]) {}
+
+    // Distance between two prepared rows.
+    fn distance(&self, a: &[F], b: &[F]) -> F;
+
+    // One-off distance between raw rows; copies both to prepare them.
+    fn between(&self, a: &[F], b: &[F]) -> F
+    where
+        F: Clone,
+    {
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        self.prepare(&mut a);
+        self.prepare(&mut b);
+        self.distance(&a, &b)
+    }
+}
+
+// Most metrics are separable: reduce a per-feature term. Terms and reductions are the
+// ordinary kernels, so they get the same promotion and accumulation rules.
+fn separable<F: Copy, T, K>(a: &[F], b: &[F], term: T, reduce: K) -> K::Output
+where
+    T: BinaryKernel<F, F>,
+    K: ReduceKernel<T::Output>,
+{
+    debug_assert_eq!(a.len(), b.len(), "distance: feature lengths differ");
+    let acc = a.iter().zip(b).fold(reduce.init(), |acc, (&x, &y)| {
+        reduce.step(acc, term.apply(x, y))
+    });
+    reduce.finish(acc)
+}
+
+const SQUARED_DIFF: ChainedKernel<SubKernel, PowiKernel> = ChainedKernel {
+    k1: SubKernel,
+    k2: PowiKernel { n: 2 },
+};
+
+const ABS_DIFF: ChainedKernel<SubKernel, AbsKernel> = ChainedKernel {
+    k1: SubKernel,
+    k2: AbsKernel,
+};
+
+/// Σ (a - b)²; cheaper than `Euclidean` and ranks neighbours the same way.
+#[derive(Debug, Clone, Copy)]
+pub struct SqEuclidean;
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for SqEuclidean {
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        separable(a, b, SQUARED_DIFF, SumKernel)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Euclidean;
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for Euclidean {
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        separable(a, b, SQUARED_DIFF, SumKernel).sqrt()
+    }
+}
+
+/// Σ |a - b|
+#[derive(Debug, Clone, Copy)]
+pub struct Manhattan;
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for Manhattan {
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        separable(a, b, ABS_DIFF, SumKernel)
+    }
+}
+
+/// max |a - b|
+#[derive(Debug, Clone, Copy)]
+pub struct Chebyshev;
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for Chebyshev {
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        separable(a, b, ABS_DIFF, MaxKernel)
+    }
+}
+
+/// 1 - cos θ, in [0, 2]. Insensitive to scale, e.g. to the gross exposure of a portfolio.
+#[derive(Debug, Clone, Copy)]
+pub struct Cosine;
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for Cosine {
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        let dot = separable(a, b, MulKernel, SumKernel);
+        let norms = separable(a, a, MulKernel, SumKernel) * separable(b, b, MulKernel, SumKernel);
+        F::one() - dot / norms.sqrt()
+    }
+}
+
+/// 1 - Pearson correlation: the cosine distance of the demeaned vectors.
+#[derive(Debug, Clone, Copy)]
+pub struct Correlation;
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for Correlation {
+    fn prepare(&self, row: &mut [F]) {
+        let acc = row
+            .iter()
+            .fold(ReduceKernel::<F>::init(&MeanKernel), |acc, &v| {
+                ReduceKernel::<F>::step(&MeanKernel, acc, v)
+            });
+        let mean = ReduceKernel::<F>::finish(&MeanKernel, acc);
+        row.iter_mut().for_each(|v| *v = *v - mean);
+    }
+
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        Cosine.distance(a, b)
+    }
+}
+
+/// √((a - b)ᵀ Σ⁻¹ (a - b)) for a covariance Σ: Euclidean after whitening by its Cholesky
+/// factor, so correlated features are not double counted. `prepare` does the whitening.
+#[derive(Debug, Clone)]
+pub struct Mahalanobis<F> {
+    // Lower-triangular L with Σ = L Lᵀ, row-major
+    chol: Arc<Vec<F>>,
+    dim: usize,
+}
+
+impl<F: Real> Mahalanobis<F> {
+    // `cov` is a row-major `dim × dim` symmetric positive definite matrix.
+    pub fn new(cov: &[F], dim: usize) -> Self {
+        assert_eq!(
+            cov.len(),
+            dim * dim,
+            "mahalanobis: covariance must be {dim}x{dim}"
+        );
+        let mut l = vec![F::zero(); dim * dim];
+        for i in 0..dim {
+            for j in 0..=i {
+                let dot = (0..j).fold(F::zero(), |acc, k| acc + l[i * dim + k] * l[j * dim + k]);
+                let rest = cov[i * dim + j] - dot;
+                if i == j {
+                    assert!(
+                        rest > F::zero(),
+                        "mahalanobis: covariance is not positive definite"
+                    );
+                    l[i * dim + i] = rest.sqrt();
+                } else {
+                    l[i * dim + j] = rest / l[j * dim + j];
+                }
+            }
+        }
+        Self {
+            chol: Arc::new(l),
+            dim,
+        }
+    }
+
+    // Diagonal covariance: per-feature variances, i.e. a standardized Euclidean distance.
+    pub fn diagonal(variances: &[F]) -> Self {
+        let dim = variances.len();
+        let mut cov = vec![F::zero(); dim * dim];
+        for (i, &v) in variances.iter().enumerate() {
+            cov[i * dim + i] = v;
+        }
+        Self::new(&cov, dim)
+    }
+}
+
+impl<F: Real + Line + Promote<F, Output = F>> DistanceMetric<F> for Mahalanobis<F> {
+    // Forward substitution L y = x in place: y[k] overwrites x[k] once it is no longer read.
+    fn prepare(&self, row: &mut [F]) {
+        let (l, n) = (&self.chol, self.dim);
+        debug_assert_eq!(row.len(), n, "mahalanobis: expected {n} features");
+        for i in 0..n {
+            let dot = (0..i).fold(F::zero(), |acc, k| acc + l[i * n + k] * row[k]);
+            row[i] = (row[i] - dot) / l[i * n + i];
+        }
+    }
+
+    // L⁻¹ is linear, so |L⁻¹a - L⁻¹b| = |L⁻¹(a - b)|.
+    fn distance(&self, a: &[F], b: &[F]) -> F {
+        Euclidean.distance(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tolerance, TradingFloat, assert_approx_eq};
+
+    fn tf(xs: &[f64]) -> Vec<TradingFloat> {
+        xs.iter().copied().map(TradingFloat::new).collect()
+    }
+
+    #[test]
+    fn test_distance_metrics() {
+        let (a, b) = (tf(&[1.0, 2.0, 3.0]), tf(&[4.0, 0.0, 3.0]));
+        let t = TradingFloat::new;
+        assert_eq!(SqEuclidean.distance(&a, &b), t(13.0));
+        assert_eq!(Euclidean.distance(&a, &b), t(13.0).sqrt());
+        assert_eq!(Manhattan.distance(&a, &b), t(5.0));
+        assert_eq!(Chebyshev.distance(&a, &b), t(3.0));
+        // cos θ = 13 / (√14 · 5)
+        assert_approx_eq!(
+            Cosine.distance(&a, &b),
+            t(1.0) - t(13.0) / (t(14.0).sqrt() * t(5.0))
+        );
+
+        // Scale and shift invariance
+        let scaled = tf(&[2.0, 4.0, 6.0]);
+        assert_approx_eq!(
+            Cosine.distance(&a, &scaled),
+            t(0.0),
+            Tolerance::Absolute(1e-15)
+        );
+        let shifted = tf(&[11.0, 12.0, 13.0]);
+        assert_approx_eq!(
+            Correlation.between(&a, &shifted),
+            t(0.0),
+            Tolerance::Absolute(1e-15)
+        );
+        let reversed = tf(&[3.0, 2.0, 1.0]);
+        assert_approx_eq!(Correlation.between(&a, &reversed), t(2.0));
+
+        // Identity covariance is Euclidean; a diagonal one standardizes each feature.
+        let identity = Mahalanobis::diagonal(&tf(&[1.0, 1.0, 1.0]));
+        assert_approx_eq!(identity.between(&a, &b), Euclidean.distance(&a, &b));
+        let standardized = Mahalanobis::diagonal(&tf(&[9.0, 4.0, 1.0]));
+        assert_approx_eq!(standardized.between(&a, &b), t(2.0).sqrt());
+
+        // Σ = [[4, 2], [2, 3]], Σ⁻¹ = [[3, -2], [-2, 4]] / 8; d² = (3·1 - 2·2·1·2 + 4·4) / 8
+        let m = Mahalanobis::new(&tf(&[4.0, 2.0, 2.0, 3.0]), 2);
+        let d = m.between(&tf(&[1.0, 2.0]), &tf(&[0.0, 0.0]));
+        assert_approx_eq!(d * d, t(11.0 / 8.0));
+    }
+}
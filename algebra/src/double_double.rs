@@ -0,0 +1,540 @@
+use super::{
+    ApproxEq, Discretization, Field, One, OrderedField, Promote, Real, Ring, Semiring,
+    TradingFloat, Zero,
+};
+use core::cmp::Ordering;
+use core::f64::consts;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// DoubleDouble is an unevaluated sum `hi + lo` of two f64, giving about 106 bits of
+/// mantissa (~32 significant digits) with the exponent range of f64.
+/// Invariants:
+/// - Both parts are finite (checked like `TradingFloat`: debug builds or `strict`)
+/// - Normalised: `hi == hi + lo` in f64, so `(hi, lo)` ordering is value ordering
+///
+/// Arithmetic uses error-free transformations (`two_sum`, `two_prod` via FMA), so
+/// `+ - * /` and `sqrt` are accurate to a few units of 2^-104 relative.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+// s + e == a + b exactly
+#[inline(always)]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+// As `two_sum`, requires |a| >= |b|
+#[inline(always)]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+// p + e == a * b exactly
+#[inline(always)]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    pub const ZERO: Self = Self::from_f64(0.0);
+    pub const ONE: Self = Self::from_f64(1.0);
+    pub const PI: Self = Self::from_parts(consts::PI, 1.2246467991473532e-16);
+    pub const E: Self = Self::from_parts(consts::E, 1.4456468917292502e-16);
+    pub const LN_2: Self = Self::from_parts(consts::LN_2, 2.3190468138462996e-17);
+    const FRAC_PI_2: Self = Self::from_parts(consts::FRAC_PI_2, 6.123233995736766e-17);
+    const TAU: Self = Self::from_parts(consts::TAU, 2.4492935982947064e-16);
+    // 2^-104, the relative spacing of DoubleDouble values
+    pub const EPSILON: f64 = 4.930380657631324e-32;
+
+    pub const fn from_f64(x: f64) -> Self {
+        Self { hi: x, lo: 0.0 }
+    }
+
+    // Caller guarantees `hi == hi + lo`.
+    const fn from_parts(hi: f64, lo: f64) -> Self {
+        Self { hi, lo }
+    }
+
+    #[inline]
+    fn normalize(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = quick_two_sum(hi, lo);
+        invariant!(
+            hi.is_finite(),
+            "DoubleDouble overflow or NaN: {} + {}",
+            hi,
+            lo
+        );
+        Self { hi, lo }
+    }
+
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    // Rounded to nearest f64
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    pub fn to_trading_float(self) -> TradingFloat {
+        TradingFloat::new(self.to_f64())
+    }
+
+    fn mul_f64(self, b: f64) -> Self {
+        let (p, e) = two_prod(self.hi, b);
+        Self::normalize(p, e + self.lo * b)
+    }
+
+    // Exact scaling by 2^k unless the result itself overflows or goes subnormal. Two factors
+    // of 2^(k/2), since 2^k alone is not a normal f64 for k >= 1024 or k <= -1023.
+    fn ldexp(self, k: i32) -> Self {
+        let (a, b) = (2f64.powi(k / 2), 2f64.powi(k - k / 2));
+        Self::from_parts(self.hi * a * b, self.lo * a * b)
+    }
+
+    fn square(self) -> Self {
+        self * self
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        Self::from_f64(x)
+    }
+}
+
+impl From<TradingFloat> for DoubleDouble {
+    fn from(x: TradingFloat) -> Self {
+        Self::from_f64(x.to_f64())
+    }
+}
+
+impl From<DoubleDouble> for TradingFloat {
+    fn from(x: DoubleDouble) -> Self {
+        x.to_trading_float()
+    }
+}
+
+impl PartialEq for DoubleDouble {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DoubleDouble {}
+
+// Parts are finite and normalised, so lexicographic order is value order
+// and -0.0 == 0.0 (consistent with `PartialEq`).
+impl Ord for DoubleDouble {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by = |a: f64, b: f64| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+        by(self.hi, other.hi).then_with(|| by(self.lo, other.lo))
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    // IEEE-style accurate addition (both parts summed with two_sum).
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (s1, s2) = two_sum(self.hi, rhs.hi);
+        let (t1, t2) = two_sum(self.lo, rhs.lo);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+        Self::normalize(s1, s2 + t2)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs.hi);
+        Self::normalize(p1, p2 + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+    // Long division: three f64 quotient digits, each correcting the previous remainder.
+    fn div(self, rhs: Self) -> Self {
+        invariant!(rhs.hi != 0.0, "DoubleDouble division by zero");
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs.mul_f64(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs.mul_f64(q2);
+        let q3 = r.hi / rhs.hi;
+        let (q1, q2) = quick_two_sum(q1, q2);
+        Self::normalize(q1, q2) + Self::from_f64(q3)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_parts(-self.hi, -self.lo)
+    }
+}
+
+impl Sum for DoubleDouble {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl Product for DoubleDouble {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl Zero for DoubleDouble {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl One for DoubleDouble {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl Semiring for DoubleDouble {}
+impl Ring for DoubleDouble {}
+impl Field for DoubleDouble {
+    #[inline]
+    fn recip(self) -> Self {
+        Self::ONE / self
+    }
+}
+
+impl OrderedField for DoubleDouble {
+    #[inline]
+    fn abs(self) -> Self {
+        if self.hi < 0.0 { -self } else { self }
+    }
+    #[inline]
+    fn signum(self) -> Self {
+        match self.hi.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => Self::ONE,
+            Some(Ordering::Less) => -Self::ONE,
+            _ => Self::ZERO,
+        }
+    }
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    #[inline]
+    fn clamp(self, lo: Self, hi: Self) -> Self {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+impl Discretization for DoubleDouble {
+    fn floor(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            Self::normalize(hi, self.lo.floor())
+        } else {
+            Self::from_f64(hi)
+        }
+    }
+
+    fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+
+    // Half away from zero, like `f64::round`
+    fn round(self) -> Self {
+        if self.hi < 0.0 {
+            -(-self).round()
+        } else {
+            (self + Self::from_f64(0.5)).floor()
+        }
+    }
+}
+
+// sin and cos of |t| <= π/4 by Taylor series.
+fn sin_cos_taylor(t: DoubleDouble) -> (DoubleDouble, DoubleDouble) {
+    let t2 = t.square();
+    let (mut sin, mut cos) = (t, DoubleDouble::ONE);
+    let (mut s_term, mut c_term) = (t, DoubleDouble::ONE);
+    let mut n = 1.0;
+    while s_term.hi.abs() > DoubleDouble::EPSILON * 1e-3
+        || c_term.hi.abs() > DoubleDouble::EPSILON * 1e-3
+    {
+        c_term = -(c_term * t2) / DoubleDouble::from_f64(n * (n + 1.0));
+        s_term = -(s_term * t2) / DoubleDouble::from_f64((n + 1.0) * (n + 2.0));
+        cos = cos + c_term;
+        sin = sin + s_term;
+        n += 2.0;
+    }
+    (sin, cos)
+}
+
+impl DoubleDouble {
+    // Reduces x to t in [-π/4, π/4] with x = t + j π/2 (mod 2π), returns (sin x, cos x).
+    fn sin_cos(self) -> (Self, Self) {
+        let r = self - Self::TAU * (self / Self::TAU).round();
+        let j = (r / Self::FRAC_PI_2).round();
+        let t = r - Self::FRAC_PI_2 * j;
+        let (s, c) = sin_cos_taylor(t);
+        match j.hi as i64 {
+            0 => (s, c),
+            1 => (c, -s),
+            -1 => (-c, s),
+            _ => (-s, -c),
+        }
+    }
+}
+
+impl DoubleDouble {
+    // x = k ln2 + r, exp(r) = exp(r / 2^9)^(2^9) with a Taylor series for the small argument.
+    // Returns (e^r - 1, k) so `expm1` keeps the low bits of small results.
+    fn exp_reduced(self) -> (Self, i32) {
+        let k = (self / Self::LN_2).round();
+        let r = (self - Self::LN_2 * k).ldexp(-9);
+
+        let mut sum = Self::ZERO;
+        let mut term = Self::ONE;
+        let mut n = 1.0;
+        while term.hi.abs() > Self::EPSILON * 1e-3 {
+            term = term * r / Self::from_f64(n);
+            sum = sum + term;
+            n += 1.0;
+        }
+        // (1 + s)^2 - 1 = 2s + s^2
+        for _ in 0..9 {
+            sum = sum + sum + sum.square();
+        }
+        (sum, k.hi as i32)
+    }
+}
+
+impl Real for DoubleDouble {
+    fn pi() -> Self {
+        Self::PI
+    }
+
+    fn e() -> Self {
+        Self::E
+    }
+
+    fn exp(self) -> Self {
+        if self.hi == 0.0 {
+            return Self::ONE;
+        }
+        let (sum, k) = self.exp_reduced();
+        let out = (sum + Self::ONE).ldexp(k);
+        invariant!(out.hi.is_finite(), "DoubleDouble exp overflow: {}", self.hi);
+        out
+    }
+
+    // One Newton step on exp(y) = x from the f64 logarithm doubles the correct digits. The
+    // step runs on x / 2^e near 1, so exp(-y) stays normal at either end of the range.
+    fn ln(self) -> Self {
+        invariant!(
+            self.hi > 0.0,
+            "DoubleDouble ln of non-positive value: {}",
+            self.hi
+        );
+        let e = self.hi.log2().floor() as i32;
+        let x = self.ldexp(-e);
+        let y = Self::from_f64(x.hi.ln());
+        y + x * (-y).exp() - Self::ONE + Self::LN_2 * Self::from_f64(e as f64)
+    }
+
+    fn sqrt(self) -> Self {
+        invariant!(
+            self.hi >= 0.0,
+            "DoubleDouble sqrt of negative value: {}",
+            self.hi
+        );
+        if self.hi == 0.0 {
+            return Self::ZERO;
+        }
+        let y = Self::from_f64(self.hi.sqrt());
+        y + (self - y.square()) / (y + y)
+    }
+
+    fn pow(self, exp: Self) -> Self {
+        (exp * self.ln()).exp()
+    }
+
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    // Newton step on the f64 angle, using whichever of sin/cos is better conditioned.
+    fn atan2(self, other: Self) -> Self {
+        if self.hi == 0.0 && other.hi == 0.0 {
+            return Self::ZERO;
+        }
+        let z = Self::from_f64(self.hi.atan2(other.hi));
+        let r = (self.square() + other.square()).sqrt();
+        let (y, x) = (self / r, other / r);
+        let (s, c) = z.sin_cos();
+        if x.hi.abs() > y.hi.abs() {
+            z + (y - s) / c
+        } else {
+            z - (x - c) / s
+        }
+    }
+
+    fn from_f64(x: f64) -> Self {
+        DoubleDouble::from_f64(x)
+    }
+
+    fn expm1(self) -> Self {
+        match self.exp_reduced() {
+            (sum, 0) => sum,
+            _ => self.exp() - Self::ONE,
+        }
+    }
+
+    // One Newton step on expm1(y) = x from the f64 value.
+    fn log1p(self) -> Self {
+        invariant!(
+            self > -Self::ONE,
+            "DoubleDouble log1p of a value <= -1: {}",
+            self.hi
+        );
+        let y = Self::from_f64(self.hi.ln_1p());
+        let em1 = y.expm1();
+        y - (em1 - self) / (em1 + Self::ONE)
+    }
+}
+
+// One ulp is EPSILON relative to the larger magnitude.
+impl ApproxEq for DoubleDouble {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (*self - *other).hi.abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        let largest = self.hi.abs().max(other.hi.abs());
+        (*self - *other).hi.abs() <= largest * max_relative
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.relative_eq(other, max_ulps as f64 * Self::EPSILON)
+    }
+}
+
+impl Promote<DoubleDouble> for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: DoubleDouble) -> Self::Output {
+        rhs
+    }
+}
+
+// Mixing with `TradingFloat` widens to DoubleDouble, which is exact.
+impl Promote<TradingFloat> for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: TradingFloat) -> Self::Output {
+        rhs.into()
+    }
+}
+
+impl Promote<DoubleDouble> for TradingFloat {
+    type Output = DoubleDouble;
+
+    fn promote_left(self) -> Self::Output {
+        self.into()
+    }
+
+    fn promote_right(rhs: DoubleDouble) -> Self::Output {
+        rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tolerance, assert_approx_eq};
+
+    type DD = DoubleDouble;
+
+    #[test]
+    fn test_double_double_precision() {
+        let tol = Tolerance::Ulps(16);
+        let dd = DD::from_f64;
+
+        // 0.1 + 0.2 - 0.3 is exact in DoubleDouble: the residual of the f64 inputs.
+        let residual = dd(0.1) + dd(0.2) - dd(0.3);
+        assert_eq!(residual.to_f64(), 2.0f64.powi(-55));
+        assert_approx_eq!(dd(1.0) / dd(3.0) * dd(3.0), DD::ONE, tol);
+        assert_approx_eq!(dd(2.0).sqrt().square(), dd(2.0), tol);
+        assert_approx_eq!(DD::ONE.exp(), DD::E, tol);
+        assert_approx_eq!(DD::E.ln(), DD::ONE, tol);
+        assert_approx_eq!(dd(10.0).exp().ln(), dd(10.0), tol);
+        // Just below f64::MAX.ln() the result is finite though 2^k alone is not.
+        let top = dd(709.5).exp();
+        assert_approx_eq!(top.to_f64(), 709.5f64.exp(), Tolerance::Relative(1e-15));
+        assert_approx_eq!(top.ln(), dd(709.5), tol);
+        assert_approx_eq!(dd(1.0).atan2(dd(1.0)) * dd(4.0), DD::PI, tol);
+        assert_approx_eq!((DD::PI / dd(6.0)).sin(), dd(0.5), tol);
+        assert_approx_eq!(DD::PI.cos(), -DD::ONE, tol);
+        assert!(DD::PI.sin().hi().abs() < 1e-31);
+
+        // Daily compounding over 30 years: f64 drifts, DoubleDouble matches exp(n ln(1 + r)).
+        let (rate, days) = (dd(0.05) / dd(365.0), 365 * 30);
+        let compounded = (0..days).fold(DD::ONE, |acc, _| acc * (DD::ONE + rate));
+        let closed_form = (dd(days as f64) * (DD::ONE + rate).ln()).exp();
+        assert_approx_eq!(compounded, closed_form, Tolerance::Relative(1e-28));
+
+        assert!(dd(1.0) + DD::from_parts(0.0, 0.0) == DD::ONE);
+        assert!(-DD::ZERO == DD::ZERO && dd(1.0) < DD::normalize(1.0, 1e-20));
+        assert_eq!(dd(-2.5).round(), dd(-3.0));
+        assert_eq!(DD::normalize(3.0, -1e-20).floor(), dd(2.0));
+        assert_eq!(TradingFloat::from(DD::PI), TradingFloat::PI);
+    }
+}
@@ -1,3 +1,7 @@
+use super::BrownianBridge;
+use core::marker::PhantomData;
+use std::sync::Arc;
+
 // Free algebra expression types
 #[derive(Debug, Clone)]
 pub struct IdentityExpr;
@@ -63,12 +67,36 @@ pub struct MapExpr<Op, K> {
     pub kernel: K,
 }
 
+// Applies `kernel(params[g], x)` where `g` is the index along `axis`,
+// or `params[0]` everywhere when `axis` is `None`. Per-axis scales, quantization, etc.
 #[derive(Debug, Clone)]
-pub struct ContractExpr<L, R, const K: usize> {
+pub struct GroupedExpr<Op, P, K> {
+    pub op: Op,
+    pub params: Arc<Vec<P>>,
+    pub axis: Option<usize>,
+    pub kernel: K,
+}
+
+// Sums `mul` products over the `K` paired axes, in whatever semiring the products live in.
+// Output axes are described by where they come from: `left_map[i]`/`right_map[i]` give
+// the source axis on each side, and an axis present on both sides is a batch axis.
+#[derive(Debug, Clone)]
+pub struct ContractExpr<
+    L,
+    R,
+    M,
+    const K: usize,
+    const R_L: usize,
+    const R_R: usize,
+    const R_OUT: usize,
+> {
     pub left: L,
     pub right: R,
+    pub mul: M,
     pub left_axes: [usize; K],
     pub right_axes: [usize; K],
+    pub left_map: [Option<usize>; R_OUT],
+    pub right_map: [Option<usize>; R_OUT],
 }
 
 // TODO: Windowing / Convolution.
@@ -82,23 +110,58 @@ pub struct ScanExpr<Op, F, K> {
     pub kernel: K,
 }
 
-// TODO: Distance metrics
-// Implementation strategy:
-// 1. Define trait `Distance<F>` with `fn dist(a, b) -> F`.
-// 2. `Euclidean` = Sqrt(Sum(Square(Sub(a, b)))) -> This can be composed of existing kernels!
-// 3. `Cosine` = Div(Dot(a, b), Mul(Norm(a), Norm(b))).
-//
-// pub trait DistanceMetric<F: Real>: Copy + Clone + 'static {}
-// #[derive(Debug, Clone, Copy)]
-// pub struct Euclidean;
-// impl<F: Real> DistanceMetric<F> for Euclidean {}
-
-// #[derive(Debug, Clone, Copy)]
-// pub struct CDistExpr<L, R, M> {
-//     pub left: L,
-//     pub right: R,
-//     _metric: PhantomData<M>,
-// }
+// Folds `axis` away with a `ReduceKernel`; the output has rank `R_IN - 1`.
+#[derive(Debug, Clone)]
+pub struct ReduceExpr<Op, K, const R_IN: usize> {
+    pub op: Op,
+    pub axis: usize,
+    pub kernel: K,
+}
+
+// All-pairs distances between the rows of two matrices. `*_axes` are the (row, feature)
+// axes of each operand; the output is [left rows, right rows].
+#[derive(Debug, Clone)]
+pub struct CDistExpr<L, R, M> {
+    pub left: L,
+    pub right: R,
+    pub metric: M,
+    pub left_axes: [usize; 2],
+    pub right_axes: [usize; 2],
+}
+
+// `dist` sampled at indices `offset..offset + numel` of `rng`, laid out row-major.
+#[derive(Debug, Clone)]
+pub struct RandExpr<G, D, const R: usize> {
+    pub rng: G,
+    pub dist: D,
+    pub shape: [usize; R],
+    pub offset: u64,
+}
+
+// `kernel` stepped along `axis` with fresh state for every lane, e.g. one path per row.
+#[derive(Debug, Clone)]
+pub struct StreamExpr<Op, K> {
+    pub op: Op,
+    pub kernel: K,
+    pub axis: usize,
+}
+
+// Points `offset..offset + paths` of `seq`, one row per path: [paths, seq.dims()].
+#[derive(Debug, Clone)]
+pub struct QuasiRandomExpr<S, F> {
+    pub seq: S,
+    pub paths: usize,
+    pub offset: u64,
+    pub _marker: PhantomData<F>,
+}
+
+// Standard normals along `axis`, in bridge order, to Brownian increments in time order.
+#[derive(Debug, Clone)]
+pub struct BridgeExpr<Op, F> {
+    pub op: Op,
+    pub bridge: BrownianBridge<F>,
+    pub axis: usize,
+}
 
 // TODO: Rewrite Rules / Pattern Matching.
 // To implement optimizations (e.g., x + 0 -> x), you need a "Rewrite" trait.
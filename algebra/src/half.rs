@@ -0,0 +1,351 @@
+use super::{ApproxEq, Narrow, One, Promote, TradingFloat, Zero};
+use core::fmt::{self, Debug, Display};
+
+// IEEE binary16: 1 sign, 5 exponent, 10 mantissa bits.
+// Round to nearest even, overflow goes to infinity, NaN stays NaN (quieted).
+fn f32_to_f16_bits(x: f32) -> u16 {
+    let bits = x.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let man = bits & 0x7f_ffff;
+
+    if exp == 0xff {
+        let nan = if man != 0 {
+            0x200 | (man >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+    let e = exp - 127 + 15;
+    if e >= 0x1f {
+        return sign | 0x7c00;
+    }
+    // Below half the smallest subnormal
+    if e < -10 {
+        return sign;
+    }
+    // Subnormal results shift the implicit bit into the mantissa; normal ones drop 13 bits.
+    let (man, shift, base) = if e <= 0 {
+        (man | 0x80_0000, (14 - e) as u32, 0)
+    } else {
+        (man, 13, (e as u16) << 10)
+    };
+    let half = 1 << (shift - 1);
+    let rem = man & ((1 << shift) - 1);
+    let mut out = base | (man >> shift) as u16;
+    // A carry out of the mantissa bumps the exponent, up to infinity; that is the right answer.
+    if rem > half || (rem == half && out & 1 == 1) {
+        out += 1;
+    }
+    sign | out
+}
+
+// Exact: every binary16 value is an f32.
+fn f16_bits_to_f32(h: u16) -> f32 {
+    let sign = ((h & 0x8000) as u32) << 16;
+    let exp = ((h >> 10) & 0x1f) as u32;
+    let man = (h & 0x3ff) as u32;
+    match exp {
+        0 => {
+            // man * 2^-24
+            let magnitude = man as f32 * f32::from_bits(0x3380_0000);
+            f32::from_bits(sign | magnitude.to_bits())
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (man << 13)),
+        _ => f32::from_bits(sign | ((exp + 112) << 23) | (man << 13)),
+    }
+}
+
+// bfloat16 is the top half of an f32, so only the mantissa is rounded.
+fn f32_to_bf16_bits(x: f32) -> u16 {
+    let bits = x.to_bits();
+    if x.is_nan() {
+        return ((bits >> 16) | 0x40) as u16;
+    }
+    let round = 0x7fff + ((bits >> 16) & 1);
+    ((bits + round) >> 16) as u16
+}
+
+fn bf16_bits_to_f32(h: u16) -> f32 {
+    f32::from_bits((h as u32) << 16)
+}
+
+// f64 -> f32 rounding to odd: keeps enough information that the second rounding
+// (to 11 or 8 bits) is the correctly rounded result of the f64.
+fn f64_to_f32_odd(x: f64) -> f32 {
+    let f = x as f32;
+    if f as f64 == x || f.to_bits() & 1 == 1 || !f.is_finite() {
+        f
+    } else if (f as f64) < x {
+        f.next_up()
+    } else {
+        f.next_down()
+    }
+}
+
+macro_rules! half_float {
+    ($(#[$doc:meta])* $name:ident, $to_bits:ident, $from_bits:ident, $one:expr) => {
+        $(#[$doc])*
+        // `PartialEq` and `Hash` compare bit patterns, so -0 != +0 and NaN == NaN.
+        // There is no arithmetic: values are widened for compute and narrowed for storage.
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+        pub struct $name(u16);
+
+        impl $name {
+            pub const ZERO: Self = Self(0);
+            pub const ONE: Self = Self($one);
+
+            pub const fn from_bits(bits: u16) -> Self {
+                Self(bits)
+            }
+
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            // Round to nearest even
+            pub fn from_f32(x: f32) -> Self {
+                Self($to_bits(x))
+            }
+
+            pub fn from_f64(x: f64) -> Self {
+                Self($to_bits(f64_to_f32_odd(x)))
+            }
+
+            // Exact
+            pub fn to_f32(self) -> f32 {
+                $from_bits(self.0)
+            }
+
+            pub fn is_finite(self) -> bool {
+                self.to_f32().is_finite()
+            }
+        }
+
+        impl From<$name> for f32 {
+            fn from(x: $name) -> f32 {
+                x.to_f32()
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(x: $name) -> f64 {
+                x.to_f32() as f64
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.to_f32()).finish()
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl Zero for $name {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl One for $name {
+            #[inline]
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Narrow<$name> for f32 {
+            #[inline(always)]
+            fn narrow(self) -> $name {
+                $name::from_f32(self)
+            }
+        }
+
+        impl Narrow<$name> for f64 {
+            #[inline(always)]
+            fn narrow(self) -> $name {
+                $name::from_f64(self)
+            }
+        }
+
+        impl Narrow<$name> for TradingFloat {
+            #[inline(always)]
+            fn narrow(self) -> $name {
+                $name::from_f64(self.to_f64())
+            }
+        }
+
+        // Compared in f32, except `ulps`, which counts values of this type.
+        impl ApproxEq for $name {
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                self.to_f32().abs_diff_eq(&other.to_f32(), epsilon)
+            }
+
+            fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+                self.to_f32().relative_eq(&other.to_f32(), max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+                if !self.is_finite() || !other.is_finite() {
+                    return false;
+                }
+                let ordered = |x: Self| {
+                    let b = x.0 as i16;
+                    if b < 0 { i16::MIN - b } else { b }
+                };
+                ordered(*self).abs_diff(ordered(*other)) as u64 <= max_ulps
+            }
+        }
+
+        // Storage type: kernels see f32 (or wider), even for two half operands.
+        impl Promote<$name> for $name {
+            type Output = f32;
+
+            #[inline(always)]
+            fn promote_left(self) -> f32 {
+                self.to_f32()
+            }
+
+            #[inline(always)]
+            fn promote_right(rhs: $name) -> f32 {
+                rhs.to_f32()
+            }
+        }
+
+        half_float!(@widen $name => f32, |x: f32| x);
+        half_float!(@widen $name => f64, |x: f32| x as f64);
+        half_float!(@widen $name => TradingFloat, |x: f32| TradingFloat::new(x as f64));
+    };
+    (@widen $name:ident => $wide:ty, $conv:expr) => {
+        impl Promote<$wide> for $name {
+            type Output = $wide;
+
+            #[inline(always)]
+            fn promote_left(self) -> $wide {
+                ($conv)(self.to_f32())
+            }
+
+            #[inline(always)]
+            fn promote_right(rhs: $wide) -> $wide {
+                rhs
+            }
+        }
+
+        impl Promote<$name> for $wide {
+            type Output = $wide;
+
+            #[inline(always)]
+            fn promote_left(self) -> $wide {
+                self
+            }
+
+            #[inline(always)]
+            fn promote_right(rhs: $name) -> $wide {
+                ($conv)(rhs.to_f32())
+            }
+        }
+    };
+}
+
+half_float!(
+    /// IEEE 754 binary16: 11-bit significand, range ±65504, subnormals down to 6e-8.
+    F16,
+    f32_to_f16_bits,
+    f16_bits_to_f32,
+    0x3c00
+);
+
+half_float!(
+    /// bfloat16: the f32 exponent range with an 8-bit significand (~3 decimal digits).
+    BF16,
+    f32_to_bf16_bits,
+    bf16_bits_to_f32,
+    0x3f80
+);
+
+impl Promote<BF16> for F16 {
+    type Output = f32;
+
+    #[inline(always)]
+    fn promote_left(self) -> f32 {
+        self.to_f32()
+    }
+
+    #[inline(always)]
+    fn promote_right(rhs: BF16) -> f32 {
+        rhs.to_f32()
+    }
+}
+
+impl Promote<F16> for BF16 {
+    type Output = f32;
+
+    #[inline(always)]
+    fn promote_left(self) -> f32 {
+        self.to_f32()
+    }
+
+    #[inline(always)]
+    fn promote_right(rhs: F16) -> f32 {
+        rhs.to_f32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReduceKernel, SumKernel};
+
+    #[test]
+    fn test_half_rounding() {
+        // Reference bit patterns from IEEE round-to-nearest-even
+        for (x, bits) in [
+            (65519.0, 0x7bff),
+            (65520.0, 0x7c00),
+            (1.0 + 2f64.powi(-11), 0x3c00),
+            (1.0 + 3.0 * 2f64.powi(-11), 0x3c02),
+            (2f64.powi(-25), 0x0000),
+            (3.0 * 2f64.powi(-26), 0x0001),
+            (0.1, 0x2e66),
+            (-2.5e-8, 0x8000),
+            (6.1e-5, 0x03ff),
+            (core::f64::consts::PI, 0x4248),
+        ] {
+            assert_eq!(F16::from_f64(x).to_bits(), bits, "{x}");
+            assert_eq!(F16::from_f32(x as f32).to_bits(), bits, "{x}");
+        }
+        // Round-trip is exact for every finite binary16 value.
+        for bits in (0..=0xffffu16).filter(|b| b & 0x7c00 != 0x7c00) {
+            assert_eq!(F16::from_f32(F16::from_bits(bits).to_f32()).to_bits(), bits);
+        }
+        assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+
+        // bf16 keeps the f32 range, and ties go to even.
+        assert_eq!(BF16::from_f32(1.0 + 2f32.powi(-8)).to_bits(), 0x3f80);
+        assert_eq!(BF16::from_f32(1.0 + 3.0 * 2f32.powi(-8)).to_bits(), 0x3f82);
+        assert_eq!(BF16::from_f64(1e30).to_bits(), 0x714a);
+        // 1 + 2^-8 + 2^-40 is above the tie: rounding via f32 must not lose the sticky bit.
+        assert_eq!(
+            BF16::from_f64(1.0 + 2f64.powi(-8) + 2f64.powi(-40)).to_bits(),
+            0x3f81
+        );
+
+        // Sums of half-precision storage accumulate in f32.
+        let xs = [F16::from_f32(0.1); 1000];
+        let sum: f32 = xs
+            .iter()
+            .fold(ReduceKernel::<F16>::init(&SumKernel), |acc, &x| {
+                ReduceKernel::<F16>::step(&SumKernel, acc, x)
+            });
+        assert!((sum - 1000.0 * F16::from_f32(0.1).to_f32()).abs() < 1e-3);
+        let narrowed: F16 = sum.narrow();
+        assert_eq!(narrowed, F16::from_f32(100.0));
+    }
+}
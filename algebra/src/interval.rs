@@ -0,0 +1,587 @@
+use super::{
+    ApproxEq, Discretization, Field, NextFloat, One, OrderedField, Promote, Real, Ring, Semiring,
+    Zero,
+};
+use core::fmt::{self, Display};
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Interval is a closed range `[lo, hi]` guaranteed to contain the true value.
+///
+/// Endpoints are computed in round-to-nearest and then widened outward: by one ulp
+/// after correctly rounded operations (`+ - * /`, `sqrt`), by two after library
+/// functions (`exp`, `ln`, `sin`, ...), which are assumed accurate to one ulp.
+///
+/// `Ord` is lexicographic on `(lo, hi)` so intervals can be sorted and used as keys;
+/// it is not the "certainly less" order. Use `lo`/`hi` for interval comparisons.
+///
+/// Domain errors (`recip` of a range containing zero, `ln` of a range reaching zero, ...)
+/// panic in every build: a result that does not enclose the true range is never acceptable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<F> {
+    lo: F,
+    hi: F,
+}
+
+impl<F: Copy> Interval<F> {
+    // Degenerate interval, exact
+    pub const fn point(x: F) -> Self {
+        Self { lo: x, hi: x }
+    }
+
+    pub fn lo(&self) -> F {
+        self.lo
+    }
+
+    pub fn hi(&self) -> F {
+        self.hi
+    }
+}
+
+impl<F: Real + NextFloat> Interval<F> {
+    pub fn new(lo: F, hi: F) -> Self {
+        invariant!(
+            lo <= hi,
+            "Interval bounds out of order: {:?} > {:?}",
+            lo,
+            hi
+        );
+        Self { lo, hi }
+    }
+
+    // Rounded-nearest inputs: widens both ends so the intended value is enclosed.
+    fn outward(lo: F, hi: F, ulps: u32) -> Self {
+        let (mut lo, mut hi) = (lo, hi);
+        for _ in 0..ulps {
+            lo = lo.next_down();
+            hi = hi.next_up();
+        }
+        Self::new(lo, hi)
+    }
+
+    pub fn mid(&self) -> F {
+        self.lo + (self.hi - self.lo) / (F::one() + F::one())
+    }
+
+    // Rounded up, so never smaller than the true width
+    pub fn width(&self) -> F {
+        (self.hi - self.lo).next_up()
+    }
+
+    pub fn contains(&self, x: F) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.contains(F::zero())
+    }
+
+    // Smallest interval containing both
+    pub fn hull(self, other: Self) -> Self {
+        Self::new(
+            OrderedField::min(self.lo, other.lo),
+            OrderedField::max(self.hi, other.hi),
+        )
+    }
+
+    // Image under a non-decreasing library function
+    fn monotone(self, f: impl Fn(F) -> F) -> Self {
+        Self::outward(f(self.lo), f(self.hi), 2)
+    }
+
+    // Image under a function that is monotone on either side of `turn`, trusted to `ulps`.
+    fn unimodal(self, f: impl Fn(F) -> F, turn: Option<F>, ulps: u32) -> Self {
+        let (a, b) = (f(self.lo), f(self.hi));
+        let (mut lo, mut hi) = (OrderedField::min(a, b), OrderedField::max(a, b));
+        if let Some(at) = turn.filter(|&at| self.contains(at)) {
+            let extremum = f(at);
+            lo = OrderedField::min(lo, extremum);
+            hi = OrderedField::max(hi, extremum);
+        }
+        Self::outward(lo, hi, ulps)
+    }
+}
+
+impl<F: Real + NextFloat + Discretization> Interval<F> {
+    // Image of a 2π-periodic function in [-1, 1] with its maximum at `max_at + 2kπ`
+    // and minimum at `min_at + 2kπ`.
+    fn periodic(self, f: impl Fn(F) -> F, max_at: Self, min_at: Self) -> Self {
+        let one = F::one();
+        let two_pi = Self::pi() + Self::pi();
+        if self.width() >= two_pi.lo {
+            return Self::new(-one, one);
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut out = Self::outward(OrderedField::min(a, b), OrderedField::max(a, b), 2);
+        if self.may_contain_phase(max_at, two_pi) {
+            out.hi = one;
+        }
+        if self.may_contain_phase(min_at, two_pi) {
+            out.lo = -one;
+        }
+        Self::new(
+            OrderedField::max(out.lo, -one),
+            OrderedField::min(out.hi, one),
+        )
+    }
+
+    // True unless `self` certainly misses every `at + k * period`.
+    // The enclosure of k can only be too wide, so this errs on the side of "contains".
+    fn may_contain_phase(self, at: Self, period: Self) -> bool {
+        let k = (self - at) / period;
+        k.lo.ceil() <= k.hi
+    }
+}
+
+impl<F: Real + NextFloat> From<F> for Interval<F> {
+    fn from(x: F) -> Self {
+        Self::point(x)
+    }
+}
+
+impl<F: Display> Display for Interval<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl<F: Real + NextFloat> Add for Interval<F> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::outward(self.lo + rhs.lo, self.hi + rhs.hi, 1)
+    }
+}
+
+impl<F: Real + NextFloat> Sub for Interval<F> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::outward(self.lo - rhs.hi, self.hi - rhs.lo, 1)
+    }
+}
+
+impl<F: Real + NextFloat> Mul for Interval<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let products = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        let lo = products.into_iter().reduce(OrderedField::min).unwrap();
+        let hi = products.into_iter().reduce(OrderedField::max).unwrap();
+        Self::outward(lo, hi, 1)
+    }
+}
+
+impl<F: Real + NextFloat> Div for Interval<F> {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.recip()
+    }
+}
+
+impl<F: Real + NextFloat> Neg for Interval<F> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+impl<F: Real + NextFloat> Sum for Interval<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<F: Real + NextFloat> Product for Interval<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<F: Zero + Copy> Zero for Interval<F> {
+    #[inline]
+    fn zero() -> Self {
+        Self::point(F::zero())
+    }
+}
+
+impl<F: One + Copy> One for Interval<F> {
+    #[inline]
+    fn one() -> Self {
+        Self::point(F::one())
+    }
+}
+
+impl<F: Real + NextFloat> Semiring for Interval<F> {}
+impl<F: Real + NextFloat> Ring for Interval<F> {}
+impl<F: Real + NextFloat> Field for Interval<F> {
+    fn recip(self) -> Self {
+        assert!(
+            !self.contains_zero(),
+            "Interval reciprocal of a range containing zero: {:?}",
+            self
+        );
+        Self::outward(self.hi.recip(), self.lo.recip(), 1)
+    }
+}
+
+// Interval extensions: each result contains f(x) for every x in the input.
+impl<F: Real + NextFloat> OrderedField for Interval<F> {
+    fn abs(self) -> Self {
+        if self.lo >= F::zero() {
+            self
+        } else if self.hi <= F::zero() {
+            -self
+        } else {
+            Self::new(F::zero(), OrderedField::max(-self.lo, self.hi))
+        }
+    }
+
+    fn signum(self) -> Self {
+        Self::new(self.lo.signum(), self.hi.signum())
+    }
+
+    fn min(self, other: Self) -> Self {
+        Self::new(
+            OrderedField::min(self.lo, other.lo),
+            OrderedField::min(self.hi, other.hi),
+        )
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::new(
+            OrderedField::max(self.lo, other.lo),
+            OrderedField::max(self.hi, other.hi),
+        )
+    }
+
+    fn clamp(self, lo: Self, hi: Self) -> Self {
+        OrderedField::min(OrderedField::max(self, lo), hi)
+    }
+}
+
+// Special functions are series and rational approximations rather than correctly rounded
+// library calls. Their endpoints are widened further: enough for the e^(-x²) rounding
+// in the erfc tails up to |x| ≈ 10.
+const SPECIAL_ULPS: u32 = 64;
+// Minimum of Γ on the positive axis
+const GAMMA_MIN_AT: f64 = 1.4616321449683622;
+
+impl<F: Real + NextFloat + Discretization> Real for Interval<F> {
+    fn pi() -> Self {
+        Self::outward(F::pi(), F::pi(), 1)
+    }
+
+    fn e() -> Self {
+        Self::outward(F::e(), F::e(), 1)
+    }
+
+    fn exp(self) -> Self {
+        let out = self.monotone(F::exp);
+        Self::new(OrderedField::max(out.lo, F::zero()), out.hi)
+    }
+
+    fn ln(self) -> Self {
+        assert!(
+            self.lo > F::zero(),
+            "Interval ln of a non-positive range: {:?}",
+            self
+        );
+        self.monotone(F::ln)
+    }
+
+    // Restricted to the domain: negative parts of the input are ignored.
+    fn sqrt(self) -> Self {
+        assert!(
+            self.hi >= F::zero(),
+            "Interval sqrt of a negative range: {:?}",
+            self
+        );
+        let lo = OrderedField::max(self.lo, F::zero()).sqrt().next_down();
+        Self::new(OrderedField::max(lo, F::zero()), self.hi.sqrt().next_up())
+    }
+
+    // Defined for positive bases only
+    fn pow(self, exp: Self) -> Self {
+        (exp * self.ln()).exp()
+    }
+
+    fn sin(self) -> Self {
+        let half_pi = Self::pi() / (Self::one() + Self::one());
+        self.periodic(F::sin, half_pi, -half_pi)
+    }
+
+    fn cos(self) -> Self {
+        self.periodic(F::cos, Self::zero(), Self::pi())
+    }
+
+    // Exact image in the right half plane; elsewhere the whole principal range.
+    fn atan2(self, other: Self) -> Self {
+        if other.lo > F::zero() {
+            let ratio = self / other;
+            let at = |t: F| t.atan2(F::one());
+            return ratio.monotone(at);
+        }
+        let pi = Self::pi();
+        Self::new(-pi.hi, pi.hi)
+    }
+
+    // Exact for scalars at least as precise as f64
+    fn from_f64(x: f64) -> Self {
+        Self::point(F::from_f64(x))
+    }
+
+    fn sinh(self) -> Self {
+        self.monotone(F::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        let out = self.unimodal(F::cosh, Some(F::zero()), 2);
+        Self::new(OrderedField::max(out.lo, F::one()), out.hi)
+    }
+
+    fn tanh(self) -> Self {
+        let one = F::one();
+        let out = self.monotone(F::tanh);
+        Self::new(
+            OrderedField::max(out.lo, -one),
+            OrderedField::min(out.hi, one),
+        )
+    }
+
+    fn atan(self) -> Self {
+        self.monotone(F::atan)
+    }
+
+    fn log1p(self) -> Self {
+        assert!(
+            self.lo > -F::one(),
+            "Interval log1p of a range reaching -1: {:?}",
+            self
+        );
+        self.monotone(F::log1p)
+    }
+
+    fn expm1(self) -> Self {
+        let out = self.monotone(F::expm1);
+        Self::new(OrderedField::max(out.lo, -F::one()), out.hi)
+    }
+
+    fn log2(self) -> Self {
+        assert!(
+            self.lo > F::zero(),
+            "Interval log2 of a non-positive range: {:?}",
+            self
+        );
+        self.monotone(F::log2)
+    }
+
+    fn log10(self) -> Self {
+        assert!(
+            self.lo > F::zero(),
+            "Interval log10 of a non-positive range: {:?}",
+            self
+        );
+        self.monotone(F::log10)
+    }
+
+    // Repeated outward-rounded products; even powers go through |x| so they stay non-negative.
+    fn powi(self, n: i32) -> Self {
+        if n % 2 != 0 {
+            return crate::special::powi(self, n);
+        }
+        let out = crate::special::powi(self.abs(), n);
+        Self::new(OrderedField::max(out.lo, F::zero()), out.hi)
+    }
+
+    fn erf(self) -> Self {
+        self.unimodal(F::erf, None, SPECIAL_ULPS)
+    }
+
+    fn erfc(self) -> Self {
+        self.unimodal(F::erfc, None, SPECIAL_ULPS)
+    }
+
+    fn norm_pdf(self) -> Self {
+        self.unimodal(F::norm_pdf, Some(F::zero()), SPECIAL_ULPS)
+    }
+
+    fn norm_cdf(self) -> Self {
+        self.unimodal(F::norm_cdf, None, SPECIAL_ULPS)
+    }
+
+    fn norm_inv_cdf(self) -> Self {
+        self.unimodal(F::norm_inv_cdf, None, SPECIAL_ULPS)
+    }
+
+    // Positive arguments only, where Γ has a single minimum.
+    // ln Γ crosses zero at 1 and 2, so its error is also bounded absolutely.
+    fn lgamma(self) -> Self {
+        assert!(
+            self.lo > F::zero(),
+            "Interval lgamma of a non-positive range: {:?}",
+            self
+        );
+        let out = self.unimodal(F::lgamma, Some(F::from_f64(GAMMA_MIN_AT)), SPECIAL_ULPS);
+        let slack = F::from_f64(SPECIAL_ULPS as f64 * f64::EPSILON);
+        Self::new((out.lo - slack).next_down(), (out.hi + slack).next_up())
+    }
+
+    fn gamma(self) -> Self {
+        assert!(
+            self.lo > F::zero(),
+            "Interval gamma of a non-positive range: {:?}",
+            self
+        );
+        self.unimodal(F::gamma, Some(F::from_f64(GAMMA_MIN_AT)), SPECIAL_ULPS)
+    }
+}
+
+impl<F: ApproxEq> ApproxEq for Interval<F> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.lo.abs_diff_eq(&other.lo, epsilon) && self.hi.abs_diff_eq(&other.hi, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.lo.relative_eq(&other.lo, max_relative) && self.hi.relative_eq(&other.hi, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.lo.ulps_eq(&other.lo, max_ulps) && self.hi.ulps_eq(&other.hi, max_ulps)
+    }
+}
+
+impl<F: Real + NextFloat> Promote<Interval<F>> for Interval<F> {
+    type Output = Interval<F>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Interval<F>) -> Self::Output {
+        rhs
+    }
+}
+
+impl<F: Real + NextFloat> Promote<F> for Interval<F> {
+    type Output = Interval<F>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: F) -> Self::Output {
+        Interval::point(rhs)
+    }
+}
+
+impl<F: Real + NextFloat> Promote<Interval<F>> for F {
+    type Output = Interval<F>;
+
+    fn promote_left(self) -> Self::Output {
+        Interval::point(self)
+    }
+
+    fn promote_right(rhs: Interval<F>) -> Self::Output {
+        rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TradingFloat;
+
+    type I = Interval<TradingFloat>;
+
+    fn iv(lo: f64, hi: f64) -> I {
+        Interval::new(TradingFloat::new(lo), TradingFloat::new(hi))
+    }
+
+    fn encloses(x: I, value: f64) -> bool {
+        x.contains(TradingFloat::new(value))
+    }
+
+    #[test]
+    fn test_interval_enclosures() {
+        // 0.1 + 0.2 rounds to 0.30000000000000004; the enclosure holds both neighbours.
+        let sum = I::from(TradingFloat::new(0.1)) + I::from(TradingFloat::new(0.2));
+        assert!(encloses(sum, 0.3) && encloses(sum, 0.1 + 0.2));
+
+        // Bid/ask uncertainty through a notional: qty * (ask - bid) / mid
+        let (bid, ask) = (iv(99.5, 99.6), iv(100.1, 100.2));
+        let qty = iv(1_000.0, 1_000.0);
+        let spread_cost = qty * (ask - bid) / ((ask + bid) / I::point(TradingFloat::new(2.0)));
+        assert!(encloses(spread_cost, 1_000.0 * 0.6 / 99.85));
+        assert!(encloses(spread_cost, 1_000.0 * 0.5 / 99.9));
+        assert!(encloses(spread_cost, 1_000.0 * 0.7 / 99.8));
+
+        // Dependency effect: x - x is not zero, but it does contain zero.
+        let x = iv(1.0, 2.0);
+        let diff = x - x;
+        assert!(diff.lo() <= TradingFloat::new(-1.0) && diff.hi() >= TradingFloat::new(1.0));
+
+        let s = iv(0.0, 3.2).sin();
+        assert_eq!(s.hi(), TradingFloat::new(1.0));
+        assert!(encloses(s, 3.2f64.sin()) && encloses(s, 0.0));
+        let c = iv(3.0, 3.5).cos();
+        assert_eq!(c.lo(), TradingFloat::new(-1.0));
+        assert!(encloses(iv(1.0, 2.0).exp().ln(), 1.0) && encloses(iv(1.0, 2.0).exp().ln(), 2.0));
+        assert!(encloses(iv(4.0, 9.0).sqrt(), 2.0) && encloses(iv(4.0, 9.0).sqrt(), 3.0));
+        assert!(encloses(
+            iv(1.0, 1.0).atan2(iv(1.0, 1.0)),
+            core::f64::consts::FRAC_PI_4
+        ));
+        assert!(encloses(I::pi(), core::f64::consts::PI));
+
+        // Even powers of a range straddling zero stay non-negative.
+        let sq = iv(-1.0, 2.0).powi(2);
+        assert!(sq.lo() == TradingFloat::ZERO && encloses(sq, 4.0));
+        let cdf = iv(-1.96, 1.96).norm_cdf();
+        assert!(encloses(cdf, 0.024997895148220435) && encloses(cdf, 0.9750021048517795));
+        assert!(encloses(iv(1.0, 3.0).gamma(), 0.8856031944108887));
+        assert!(encloses(iv(1.0, 2.0).lgamma(), 0.0));
+
+        // MAX + 1 rounds to MAX; only an unbounded end encloses the true sum.
+        let one = I::point(TradingFloat::new(1.0));
+        let top = I::point(TradingFloat::new(f64::MAX)) + one;
+        assert_eq!(top.hi().to_f64(), f64::INFINITY);
+        assert_eq!(top.hi().next_up(), top.hi());
+        let bottom = I::point(TradingFloat::new(f64::MIN)) - one;
+        assert_eq!(bottom.lo().to_f64(), f64::NEG_INFINITY);
+        assert_eq!(bottom.lo().next_down(), bottom.lo());
+    }
+
+    // Out-of-domain inputs panic even without debug assertions or `strict`; a NaN or
+    // inverted result would not enclose anything.
+    #[test]
+    fn test_interval_domain_errors() {
+        use std::panic::catch_unwind;
+
+        let fails = |f: fn(I) -> I, x: I| catch_unwind(|| f(x)).is_err();
+        assert!(fails(I::recip, iv(-1.0, 1.0)));
+        assert!(fails(I::recip, iv(0.0, 1.0)));
+        assert!(fails(I::ln, iv(-1.0, 2.0)));
+        assert!(fails(I::ln, iv(0.0, 2.0)));
+        assert!(fails(I::log2, iv(-1.0, 2.0)));
+        assert!(fails(I::log10, iv(-1.0, 2.0)));
+        assert!(fails(I::log1p, iv(-1.0, 2.0)));
+        assert!(fails(I::sqrt, iv(-2.0, -1.0)));
+        assert!(fails(I::gamma, iv(-0.5, 2.0)));
+        assert!(fails(I::lgamma, iv(-0.5, 2.0)));
+        assert!(fails(|x| I::one() / x, iv(-1.0, 1.0)));
+
+        // The boundaries themselves are in the domain.
+        assert!(encloses(iv(1.0, 4.0).recip(), 0.25));
+        assert!(encloses(iv(-4.0, -1.0).recip(), -1.0));
+        assert!(encloses(iv(-1.0, 4.0).sqrt(), 0.0));
+        assert!(encloses(iv(1e-300, 1.0).ln(), 0.0));
+    }
+}
@@ -1,17 +1,16 @@
 use super::{
-    BinaryKernel, Data, One, OrderedField, Promote, Real, ReduceKernel, Ring, Semiring,
-    StreamKernel, UnaryKernel, Zero,
+    BinaryKernel, Data, DifferentiableKernel, Field, LANES, Lanes, Line, Narrow, One,
+    OrderFreeMerge, OrderedField, Promote, QuantParams, Quantized, Real, ReduceKernel, ReduceOrder,
+    Ring, Semiring, StreamKernel, UnaryKernel, Zero,
 };
-
-// TODO: SIMD / Vectorization Support.
-// Implement a Line trait
+use core::marker::PhantomData;
 
 #[derive(Debug, Clone, Copy)]
 pub struct AddKernel;
 impl<L, R> BinaryKernel<L, R> for AddKernel
 where
     L: Promote<R>,
-    L::Output: Semiring,
+    L::Output: Semiring + Line,
 {
     type Output = L::Output;
 
@@ -22,6 +21,11 @@ where
 
         l_prom + r_prom
     }
+
+    #[inline(always)]
+    fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<Self::Output> {
+        L::Output::add_lanes(lhs.map(L::promote_left), rhs.map(L::promote_right))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -31,7 +35,7 @@ pub struct ScaleKernel<S: Data> {
 impl<In, S> UnaryKernel<In> for ScaleKernel<S>
 where
     In: Promote<S>,
-    In::Output: Semiring,
+    In::Output: Semiring + Line,
     S: Data,
 {
     type Output = In::Output;
@@ -42,6 +46,12 @@ where
         let s_prom = In::promote_right(self.factor);
         x_prom * s_prom
     }
+
+    #[inline(always)]
+    fn apply_lanes(&self, x: Lanes<In>) -> Lanes<Self::Output> {
+        let s_prom = In::promote_right(self.factor);
+        In::Output::mul_lanes(x.map(In::promote_left), [s_prom; LANES])
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -49,7 +59,7 @@ pub struct MulKernel;
 impl<L, R> BinaryKernel<L, R> for MulKernel
 where
     L: Promote<R>,
-    L::Output: Semiring,
+    L::Output: Semiring + Line,
 {
     type Output = L::Output;
 
@@ -59,17 +69,42 @@ where
         let r_prom = L::promote_right(rhs);
         l_prom * r_prom
     }
+
+    #[inline(always)]
+    fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<Self::Output> {
+        L::Output::mul_lanes(lhs.map(L::promote_left), rhs.map(L::promote_right))
+    }
 }
 
-// TODO: DivKernel.
-// Missing implementation for Division. Requires `L::Output: Field` (or Real).
+#[derive(Debug, Clone, Copy)]
+pub struct DivKernel;
+impl<L, R> BinaryKernel<L, R> for DivKernel
+where
+    L: Promote<R>,
+    L::Output: Field + Line,
+{
+    type Output = L::Output;
+
+    #[inline(always)]
+    fn apply(&self, lhs: L, rhs: R) -> Self::Output {
+        let l_prom = lhs.promote_left();
+        let r_prom = L::promote_right(rhs);
+        l_prom / r_prom
+    }
+
+    #[inline(always)]
+    fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<Self::Output> {
+        L::Output::div_lanes(lhs.map(L::promote_left), rhs.map(L::promote_right))
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct SumKernel;
+impl OrderFreeMerge for SumKernel {}
 impl<In> ReduceKernel<In> for SumKernel
 where
     In: Promote<In>,
-    In::Output: Semiring,
+    In::Output: Semiring + Line,
 {
     type Acc = In::Output;
     type Output = In::Output;
@@ -79,9 +114,8 @@ where
         Self::Acc::zero()
     }
 
-    // TODO: Numerical Stability.
-    // Naive accumulation results in significant precision loss.
-    // Implement Kahan Summation or Pairwise Summation inside `step` or `Acc`.
+    // Naive accumulation: error grows linearly with the input length.
+    // Use `CompensatedSumKernel` and/or `Pairwise` for long float series.
     #[inline(always)]
     fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
         acc + x.promote_left()
@@ -91,18 +125,202 @@ where
         acc1 + acc2
     }
 
+    #[inline(always)]
+    fn step_lanes(&self, acc: Lanes<Self::Acc>, x: Lanes<In>) -> Lanes<Self::Acc> {
+        Self::Acc::add_lanes(acc, x.map(In::promote_left))
+    }
+
     #[inline(always)]
     fn finish(&self, acc: Self::Acc) -> Self::Output {
         acc
     }
 }
 
+/// Neumaier-compensated running sum.
+/// `comp` collects the low-order bits lost by each addition to `sum`,
+/// so the error of `value()` stays O(1) ulps independent of the input length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Compensated<F> {
+    pub sum: F,
+    pub comp: F,
+}
+
+impl<F: OrderedField> Compensated<F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            sum: F::zero(),
+            comp: F::zero(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn push(self, x: F) -> Self {
+        let t = self.sum + x;
+        let lost = if self.sum.abs() >= x.abs() {
+            (self.sum - t) + x
+        } else {
+            (x - t) + self.sum
+        };
+        Self {
+            sum: t,
+            comp: self.comp + lost,
+        }
+    }
+
+    #[inline(always)]
+    pub fn merge(self, other: Self) -> Self {
+        let merged = self.push(other.sum);
+        Self {
+            sum: merged.sum,
+            comp: merged.comp + other.comp,
+        }
+    }
+
+    #[inline(always)]
+    pub fn value(self) -> F {
+        self.sum + self.comp
+    }
+}
+
+impl<F: OrderedField> Default for Compensated<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompensatedSumKernel;
+impl<In> ReduceKernel<In> for CompensatedSumKernel
+where
+    In: Promote<In>,
+    In::Output: OrderedField,
+{
+    type Acc = Compensated<In::Output>;
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        Compensated::new()
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        acc.push(x.promote_left())
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        acc1.merge(acc2)
+    }
+
+    #[inline(always)]
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        acc.value()
+    }
+}
+
+/// Runs the wrapped reduction as a balanced tree instead of a left fold.
+/// e.g. `Pairwise::new(SumKernel)` or `Pairwise::new(CompensatedSumKernel)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Pairwise<K> {
+    pub kernel: K,
+    pub block: usize,
+}
+
+impl<K> Pairwise<K> {
+    // Leaves are folded sequentially; small enough to stay in L1, large enough to amortize recursion.
+    pub const DEFAULT_BLOCK: usize = 128;
+
+    pub fn new(kernel: K) -> Self {
+        Self {
+            kernel,
+            block: Self::DEFAULT_BLOCK,
+        }
+    }
+}
+
+impl<In, K> ReduceKernel<In> for Pairwise<K>
+where
+    K: ReduceKernel<In>,
+{
+    type Acc = K::Acc;
+    type Output = K::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        self.kernel.init()
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        self.kernel.step(acc, x)
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        self.kernel.merge(acc1, acc2)
+    }
+
+    #[inline(always)]
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        self.kernel.finish(acc)
+    }
+
+    fn order(&self) -> ReduceOrder {
+        ReduceOrder::Pairwise {
+            block: self.block.max(1),
+        }
+    }
+}
+
+/// Folds `LANES` interleaved partials with the wrapped kernel's `step_lanes`, e.g.
+/// `Lanewise(SumKernel)` for a SIMD sum. Reassociates like `Pairwise`, so it is opt-in;
+/// the wrapped kernel must be `OrderFreeMerge`.
+#[derive(Debug, Clone, Copy)]
+pub struct Lanewise<K>(pub K);
+
+impl<In, K> ReduceKernel<In> for Lanewise<K>
+where
+    K: ReduceKernel<In> + OrderFreeMerge,
+{
+    type Acc = K::Acc;
+    type Output = K::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        self.0.init()
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        self.0.step(acc, x)
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        self.0.merge(acc1, acc2)
+    }
+
+    #[inline(always)]
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        self.0.finish(acc)
+    }
+
+    #[inline(always)]
+    fn step_lanes(&self, acc: Lanes<Self::Acc>, x: Lanes<In>) -> Lanes<Self::Acc> {
+        self.0.step_lanes(acc, x)
+    }
+
+    fn order(&self) -> ReduceOrder {
+        ReduceOrder::Lanes
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ProductKernel;
+impl OrderFreeMerge for ProductKernel {}
 impl<In> ReduceKernel<In> for ProductKernel
 where
     In: Promote<In>,
-    In::Output: Semiring,
+    In::Output: Semiring + Line,
 {
     type Acc = In::Output;
     type Output = In::Output;
@@ -121,6 +339,11 @@ where
         acc1 * acc2
     }
 
+    #[inline(always)]
+    fn step_lanes(&self, acc: Lanes<Self::Acc>, x: Lanes<In>) -> Lanes<Self::Acc> {
+        Self::Acc::mul_lanes(acc, x.map(In::promote_left))
+    }
+
     #[inline(always)]
     fn finish(&self, acc: Self::Acc) -> Self::Output {
         acc
@@ -132,7 +355,7 @@ pub struct SubKernel;
 impl<L, R> BinaryKernel<L, R> for SubKernel
 where
     L: Promote<R>,
-    L::Output: Ring,
+    L::Output: Ring + Line,
 {
     type Output = L::Output;
 
@@ -142,6 +365,11 @@ where
         let r_prom = L::promote_right(rhs);
         l_prom - r_prom
     }
+
+    #[inline(always)]
+    fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<Self::Output> {
+        L::Output::sub_lanes(lhs.map(L::promote_left), rhs.map(L::promote_right))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -191,51 +419,343 @@ where
     }
 }
 
-// TODO: Kernel Fusion / Composition.
-// Fusion is critical for memory bandwidth efficiency.
-//
-// 1. Define a `FusedKernel` trait that can compose `Unary + Unary` or `Binary + Unary`.
-// 2. Implement `Compose` struct:
-//
-// pub struct ChainedKernel<K1, K2> { k1: K1, k2: K2 }
-// impl<T, K1, K2> UnaryKernel<T> for ChainedKernel<K1, K2>
-// where K1: UnaryKernel<T>, K2: UnaryKernel<K1::Output> {
-//     fn apply(&self, x: T) -> Self::Output {
-//         self.k2.apply(self.k1.apply(x))
-//     }
-// }
-//
-// 3. Add builder methods to the Tensor struct (e.g., `x.add(y).relu()` should produce one loop).
+// Element-wise `Real` functions, e.g. `tensor.map(NormCdfKernel)`.
+macro_rules! real_unary_kernels {
+    ($($name:ident => $method:ident),* $(,)?) => {$(
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+        impl<In> UnaryKernel<In> for $name
+        where
+            In: Promote<In>,
+            In::Output: Real,
+        {
+            type Output = In::Output;
+
+            #[inline(always)]
+            fn apply(&self, x: In) -> Self::Output {
+                x.promote_left().$method()
+            }
+        }
+    )*};
+}
+
+real_unary_kernels!(
+    ExpKernel => exp,
+    LnKernel => ln,
+    SqrtKernel => sqrt,
+    SinKernel => sin,
+    CosKernel => cos,
+    SinhKernel => sinh,
+    CoshKernel => cosh,
+    TanhKernel => tanh,
+    AtanKernel => atan,
+    Log1pKernel => log1p,
+    Expm1Kernel => expm1,
+    Log2Kernel => log2,
+    Log10Kernel => log10,
+    ErfKernel => erf,
+    ErfcKernel => erfc,
+    NormPdfKernel => norm_pdf,
+    NormCdfKernel => norm_cdf,
+    NormInvCdfKernel => norm_inv_cdf,
+    LgammaKernel => lgamma,
+    GammaKernel => gamma,
+);
 
-// TODO: JVP (Jacobian-Vector Product) and VJP (Vector-Jacobian Product).
-// For Automatic Differentiation:
-// 1. Each Kernel struct needs an associated method (or trait implementation)
-//    that defines its derivative.
+#[derive(Debug, Clone, Copy)]
+pub struct PowiKernel {
+    pub n: i32,
+}
+impl<In> UnaryKernel<In> for PowiKernel
+where
+    In: Promote<In>,
+    In::Output: Real,
+{
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn apply(&self, x: In) -> Self::Output {
+        x.promote_left().powi(self.n)
+    }
+}
+
+// atan2(lhs, rhs): angle of the point (rhs, lhs)
+#[derive(Debug, Clone, Copy)]
+pub struct Atan2Kernel;
+impl<L, R> BinaryKernel<L, R> for Atan2Kernel
+where
+    L: Promote<R>,
+    L::Output: Real,
+{
+    type Output = L::Output;
+
+    #[inline(always)]
+    fn apply(&self, lhs: L, rhs: R) -> Self::Output {
+        let l_prom = lhs.promote_left();
+        let r_prom = L::promote_right(rhs);
+        l_prom.atan2(r_prom)
+    }
+}
+
+// Converts to the compute type, e.g. `F16` -> `f32`.
+#[derive(Debug, Clone, Copy)]
+pub struct WidenKernel;
+impl<In> UnaryKernel<In> for WidenKernel
+where
+    In: Promote<In>,
+{
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn apply(&self, x: In) -> Self::Output {
+        x.promote_left()
+    }
+}
+
+// Rounds into the storage type `T`, e.g. `f32` -> `F16`.
+#[derive(Debug, Clone, Copy)]
+pub struct NarrowKernel<T> {
+    _target: PhantomData<T>,
+}
+
+impl<T> NarrowKernel<T> {
+    pub fn new() -> Self {
+        Self {
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for NarrowKernel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<In, T> UnaryKernel<In> for NarrowKernel<T>
+where
+    In: Narrow<T>,
+    T: Data,
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn apply(&self, x: In) -> T {
+        x.narrow()
+    }
+}
+
+// `(params, x) -> q`: pairs with a per-group parameter stream, see `GroupedExpr`.
+#[derive(Debug, Clone, Copy)]
+pub struct QuantizeKernel<Q> {
+    _target: PhantomData<Q>,
+}
+
+impl<Q> QuantizeKernel<Q> {
+    pub fn new() -> Self {
+        Self {
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<Q> Default for QuantizeKernel<Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q: Quantized> BinaryKernel<QuantParams, f32> for QuantizeKernel<Q> {
+    type Output = Q;
+
+    #[inline(always)]
+    fn apply(&self, params: QuantParams, x: f32) -> Q {
+        params.quantize(x)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DequantizeKernel;
+impl<Q: Quantized> BinaryKernel<QuantParams, Q> for DequantizeKernel {
+    type Output = f32;
+
+    #[inline(always)]
+    fn apply(&self, params: QuantParams, q: Q) -> f32 {
+        params.dequantize(q)
+    }
+}
+
+// Product of zero-point-corrected integers. Summed in i32 it is the exact integer GEMM;
+// the scales are applied once to the accumulated result.
+#[derive(Debug, Clone, Copy)]
+pub struct QMulKernel {
+    pub lhs_zero: i32,
+    pub rhs_zero: i32,
+}
+
+impl<L: Quantized, R: Quantized> BinaryKernel<L, R> for QMulKernel {
+    type Output = i32;
+
+    #[inline(always)]
+    fn apply(&self, lhs: L, rhs: R) -> i32 {
+        (lhs.to_i32() - self.lhs_zero) * (rhs.to_i32() - self.rhs_zero)
+    }
+}
+
+// Comparisons produce `bool` masks; operands are promoted to a common type first.
+// NaN compares false everywhere (so `lt` and `ge` are not complements on NaN).
+macro_rules! compare_kernels {
+    ($($(#[$doc:meta])* $name:ident => $op:tt, $lanes:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name;
+            impl<L, R> BinaryKernel<L, R> for $name
+            where
+                L: Promote<R>,
+                L::Output: PartialOrd + Line,
+            {
+                type Output = bool;
+
+                #[inline(always)]
+                fn apply(&self, lhs: L, rhs: R) -> bool {
+                    lhs.promote_left() $op L::promote_right(rhs)
+                }
+
+                #[inline(always)]
+                fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<bool> {
+                    L::Output::$lanes(lhs.map(L::promote_left), rhs.map(L::promote_right))
+                }
+            }
+        )*
+    };
+}
+
+compare_kernels!(
+    GtKernel => >, gt_lanes,
+    GeKernel => >=, ge_lanes,
+    LtKernel => <, lt_lanes,
+    LeKernel => <=, le_lanes,
+    // Exact equality; see `ApproxEq` for tolerances.
+    EqKernel => ==, eq_lanes,
+);
+
+// Non-short-circuiting, so the loops stay branch free.
+#[derive(Debug, Clone, Copy)]
+pub struct AndKernel;
+impl BinaryKernel<bool, bool> for AndKernel {
+    type Output = bool;
+
+    #[inline(always)]
+    fn apply(&self, lhs: bool, rhs: bool) -> bool {
+        lhs & rhs
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OrKernel;
+impl BinaryKernel<bool, bool> for OrKernel {
+    type Output = bool;
+
+    #[inline(always)]
+    fn apply(&self, lhs: bool, rhs: bool) -> bool {
+        lhs | rhs
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NotKernel;
+impl UnaryKernel<bool> for NotKernel {
+    type Output = bool;
+
+    #[inline(always)]
+    fn apply(&self, x: bool) -> bool {
+        !x
+    }
+}
+
+/// `k2` applied to the output of `k1`, in one call. Unary after unary is again unary,
+/// unary after binary is again binary; evaluation builds these to fuse chained maps.
+#[derive(Debug, Clone, Copy)]
+pub struct ChainedKernel<K1, K2> {
+    pub k1: K1,
+    pub k2: K2,
+}
+
+impl<In, K1, K2> UnaryKernel<In> for ChainedKernel<K1, K2>
+where
+    K1: UnaryKernel<In>,
+    K2: UnaryKernel<K1::Output>,
+{
+    type Output = K2::Output;
+
+    #[inline(always)]
+    fn apply(&self, x: In) -> Self::Output {
+        self.k2.apply(self.k1.apply(x))
+    }
+
+    #[inline(always)]
+    fn apply_lanes(&self, x: Lanes<In>) -> Lanes<Self::Output> {
+        self.k2.apply_lanes(self.k1.apply_lanes(x))
+    }
+}
+
+impl<L, R, K1, K2> BinaryKernel<L, R> for ChainedKernel<K1, K2>
+where
+    K1: BinaryKernel<L, R>,
+    K2: UnaryKernel<K1::Output>,
+{
+    type Output = K2::Output;
+
+    #[inline(always)]
+    fn apply(&self, lhs: L, rhs: R) -> Self::Output {
+        self.k2.apply(self.k1.apply(lhs, rhs))
+    }
+
+    #[inline(always)]
+    fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<Self::Output> {
+        self.k2.apply_lanes(self.k1.apply_lanes(lhs, rhs))
+    }
+}
+
+// Chain rule
+impl<F, K1, K2> DifferentiableKernel<F> for ChainedKernel<K1, K2>
+where
+    F: Semiring,
+    K1: DifferentiableKernel<F>,
+    K2: DifferentiableKernel<F>,
+{
+    #[inline(always)]
+    fn derivative(&self, x: F) -> F {
+        self.k2.derivative(self.k1.apply(x)) * self.k1.derivative(x)
+    }
+}
+
+// Reverse-mode step of a unary map: (grad_out, x) -> grad_out * f'(x)
+#[derive(Debug, Clone, Copy)]
+pub struct VjpKernel<K> {
+    pub kernel: K,
+}
+
+impl<F, K> BinaryKernel<F, F> for VjpKernel<K>
+where
+    F: Semiring,
+    K: DifferentiableKernel<F>,
+{
+    type Output = F;
+
+    #[inline(always)]
+    fn apply(&self, grad: F, x: F) -> F {
+        grad * self.kernel.derivative(x)
+    }
+}
+
+// TODO: JVP (Jacobian-Vector Product) and VJP for binary kernels.
+// Unary kernels implement `DifferentiableKernel`; binary ones need both partials:
 //
-// pub trait DifferentiableKernel<L, R>: BinaryKernel<L, R> {
+// pub trait DifferentiableBinaryKernel<L, R>: BinaryKernel<L, R> {
 //     fn vjp(&self, lhs: L, rhs: R, grad_out: Self::Output) -> (L, R);
 // }
 //
-// 2. Example for MulKernel:
+// Example for MulKernel:
 //    vjp(lhs, rhs, g) -> (g * rhs, g * lhs)
-
-// TODO: McCulloch–Pitts neuron (hard step activation)
-// NOTE: Non-differentiable. Use only for inference or
-// provide a straight-through estimator for training.
-// pub struct StepKernel;
-
-// impl<F> Kernel<F> for StepKernel
-// where
-//     F: Real + PartialOrd,
-// {
-//     type Output = F;
-
-//     #[inline]
-//     fn apply(x: F) -> F {
-//         if x > F::zero() {
-//             F::one()
-//         } else {
-//             F::zero()
-//         }
-//     }
-// }
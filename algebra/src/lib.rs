@@ -1,17 +1,64 @@
 #![feature(generic_const_exprs)]
+#![feature(portable_simd)]
 #![allow(incomplete_features)]
+
+// Scalar invariants: always checked in debug builds, in release only with the `strict` feature.
+macro_rules! invariant {
+    ($($arg:tt)*) => {
+        if cfg!(any(debug_assertions, feature = "strict")) {
+            assert!($($arg)*);
+        }
+    };
+}
+
+pub mod activation;
+pub mod checked;
+pub mod complex;
+pub mod decimal;
+pub mod distance;
+pub mod double_double;
 pub mod free;
+pub mod half;
+pub mod interval;
 pub mod kernel;
+pub mod line;
 pub mod linear;
+pub mod logspace;
 pub mod manifold;
+pub mod primitive;
+pub mod qmc;
+pub mod quant;
+pub mod random;
+pub mod rational;
 pub mod scalar;
+pub mod simulation;
+mod special;
+pub mod stats;
 pub mod symbolic;
 pub mod traits;
+pub mod tropical;
 
+pub use activation::*;
+pub use checked::Checked;
+pub use complex::Complex;
+pub use decimal::Decimal;
+pub use distance::*;
+pub use double_double::DoubleDouble;
 pub use free::*;
+pub use half::{BF16, F16};
+pub use interval::Interval;
 pub use kernel::*;
+pub use line::*;
 pub use linear::*;
+pub use logspace::LogSpace;
 pub use manifold::*;
+pub use qmc::*;
+pub use quant::{QuantParams, Quantized};
+pub use random::*;
+pub use rational::Rational;
 pub use scalar::TradingFloat;
+pub use simulation::*;
+pub use stats::*;
 pub use symbolic::*;
 pub use traits::*;
+pub use tropical::{MaxPlus, MinPlus};
@@ -0,0 +1,255 @@
+use super::{
+    Checked, Complex, Data, Decimal, DoubleDouble, Interval, LogSpace, MaxPlus, MinPlus, Rational,
+    TradingFloat,
+};
+use core::ops::{Add, Div, Mul, Sub};
+use core::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+use core::simd::{Select, Simd};
+
+// Width of the fixed-size chunks kernels see through `apply_lanes`/`step_lanes`.
+// 8 lanes fill a 256-bit register with f32 and two with f64.
+pub const LANES: usize = 8;
+
+pub type Lanes<T> = [T; LANES];
+
+#[inline(always)]
+pub fn zip_lanes<A: Copy, B: Copy, C>(a: Lanes<A>, b: Lanes<B>, f: impl Fn(A, B) -> C) -> Lanes<C> {
+    core::array::from_fn(|i| f(a[i], b[i]))
+}
+
+/// Element-wise operations on a whole chunk of `LANES` values.
+/// Every method has a scalar default, so an empty `impl Line for T {}` is always correct;
+/// primitive numbers override them with portable SIMD. Kernels reach these through
+/// their `apply_lanes`/`step_lanes` overrides, and only those kernels require `Line`.
+pub trait Line: Data {
+    #[inline(always)]
+    fn add_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self>
+    where
+        Self: Add<Output = Self>,
+    {
+        zip_lanes(a, b, |x, y| x + y)
+    }
+
+    #[inline(always)]
+    fn sub_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self>
+    where
+        Self: Sub<Output = Self>,
+    {
+        zip_lanes(a, b, |x, y| x - y)
+    }
+
+    #[inline(always)]
+    fn mul_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self>
+    where
+        Self: Mul<Output = Self>,
+    {
+        zip_lanes(a, b, |x, y| x * y)
+    }
+
+    #[inline(always)]
+    fn div_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self>
+    where
+        Self: Div<Output = Self>,
+    {
+        zip_lanes(a, b, |x, y| x / y)
+    }
+
+    #[inline(always)]
+    fn lt_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool>
+    where
+        Self: PartialOrd,
+    {
+        zip_lanes(a, b, |x, y| x < y)
+    }
+
+    #[inline(always)]
+    fn le_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool>
+    where
+        Self: PartialOrd,
+    {
+        zip_lanes(a, b, |x, y| x <= y)
+    }
+
+    #[inline(always)]
+    fn gt_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool>
+    where
+        Self: PartialOrd,
+    {
+        zip_lanes(a, b, |x, y| x > y)
+    }
+
+    #[inline(always)]
+    fn ge_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool>
+    where
+        Self: PartialOrd,
+    {
+        zip_lanes(a, b, |x, y| x >= y)
+    }
+
+    #[inline(always)]
+    fn eq_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool> {
+        zip_lanes(a, b, |x, y| x == y)
+    }
+
+    // `x` where `x < acc`, else `acc`; a NaN `x` never wins (unlike IEEE minNum).
+    #[inline(always)]
+    fn min_lanes(acc: Lanes<Self>, x: Lanes<Self>) -> Lanes<Self>
+    where
+        Self: PartialOrd,
+    {
+        zip_lanes(acc, x, |acc, x| if x < acc { x } else { acc })
+    }
+
+    // `x` where `x > acc`, else `acc`
+    #[inline(always)]
+    fn max_lanes(acc: Lanes<Self>, x: Lanes<Self>) -> Lanes<Self>
+    where
+        Self: PartialOrd,
+    {
+        zip_lanes(acc, x, |acc, x| if x > acc { x } else { acc })
+    }
+}
+
+macro_rules! simd_compare {
+    () => {
+        #[inline(always)]
+        fn lt_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool> {
+            Simd::from_array(a).simd_lt(Simd::from_array(b)).to_array()
+        }
+
+        #[inline(always)]
+        fn le_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool> {
+            Simd::from_array(a).simd_le(Simd::from_array(b)).to_array()
+        }
+
+        #[inline(always)]
+        fn gt_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool> {
+            Simd::from_array(a).simd_gt(Simd::from_array(b)).to_array()
+        }
+
+        #[inline(always)]
+        fn ge_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool> {
+            Simd::from_array(a).simd_ge(Simd::from_array(b)).to_array()
+        }
+
+        #[inline(always)]
+        fn eq_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<bool> {
+            Simd::from_array(a).simd_eq(Simd::from_array(b)).to_array()
+        }
+
+        #[inline(always)]
+        fn min_lanes(acc: Lanes<Self>, x: Lanes<Self>) -> Lanes<Self> {
+            let (acc, x) = (Simd::from_array(acc), Simd::from_array(x));
+            x.simd_lt(acc).select(x, acc).to_array()
+        }
+
+        #[inline(always)]
+        fn max_lanes(acc: Lanes<Self>, x: Lanes<Self>) -> Lanes<Self> {
+            let (acc, x) = (Simd::from_array(acc), Simd::from_array(x));
+            x.simd_gt(acc).select(x, acc).to_array()
+        }
+    };
+}
+
+macro_rules! simd_float_line {
+    ($($t:ty),*) => {
+        $(
+            impl Line for $t {
+                #[inline(always)]
+                fn add_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self> {
+                    (Simd::from_array(a) + Simd::from_array(b)).to_array()
+                }
+
+                #[inline(always)]
+                fn sub_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self> {
+                    (Simd::from_array(a) - Simd::from_array(b)).to_array()
+                }
+
+                #[inline(always)]
+                fn mul_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self> {
+                    (Simd::from_array(a) * Simd::from_array(b)).to_array()
+                }
+
+                #[inline(always)]
+                fn div_lanes(a: Lanes<Self>, b: Lanes<Self>) -> Lanes<Self> {
+                    (Simd::from_array(a) / Simd::from_array(b)).to_array()
+                }
+
+                simd_compare!();
+            }
+        )*
+    };
+}
+
+// Integer arithmetic stays scalar: SIMD adds wrap, while `+` panics on overflow in
+// debug builds. Comparisons and min/max cannot overflow.
+macro_rules! simd_int_line {
+    ($($t:ty),*) => {
+        $(
+            impl Line for $t {
+                simd_compare!();
+            }
+        )*
+    };
+}
+
+simd_float_line!(f32, f64);
+simd_int_line!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl Line for u128 {}
+impl Line for i128 {}
+impl Line for bool {}
+
+// The crate's own scalars take the scalar defaults. A type outside the crate opts in the
+// same way, with an empty impl, to use the element-wise and reduction kernels.
+impl Line for TradingFloat {}
+impl Line for DoubleDouble {}
+impl Line for Rational {}
+impl<const SCALE: u32> Line for Decimal<SCALE> {}
+impl<F: Data> Line for Checked<F> {}
+impl<F: Data> Line for Complex<F> {}
+impl<F: Data> Line for Interval<F> {}
+impl<F: Data> Line for LogSpace<F> {}
+impl<F: Data> Line for MaxPlus<F> {}
+impl<F: Data> Line for MinPlus<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TradingFloat;
+
+    // SIMD overrides agree with the scalar defaults, NaN included.
+    #[test]
+    fn test_simd_lanes_match_scalar() {
+        let a = [1.0, -2.0, f64::NAN, 4.0, 0.0, -0.0, 7.5, f64::INFINITY];
+        let b = [1.0, 3.0, 1.0, f64::NAN, -0.0, 0.0, 2.5, 1.0];
+        let tf = |xs: Lanes<f64>| xs.map(TradingFloat::new);
+
+        assert_eq!(f64::lt_lanes(a, b), zip_lanes(a, b, |x, y| x < y));
+        assert_eq!(f64::ge_lanes(a, b), zip_lanes(a, b, |x, y| x >= y));
+        assert_eq!(f64::eq_lanes(a, b), zip_lanes(a, b, |x, y| x == y));
+        let sum = f64::add_lanes(a, b);
+        let quotient = f64::div_lanes(a, b);
+        let same = |x: f64, y: f64| x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan());
+        for i in 0..LANES {
+            assert!(same(sum[i], a[i] + b[i]));
+            assert!(same(quotient[i], a[i] / b[i]));
+        }
+        // A NaN accumulator stays, a NaN candidate never replaces.
+        let min = f64::min_lanes(a, b);
+        assert!(min[2].is_nan());
+        assert_eq!(min[3], 4.0);
+        assert_eq!((min[1], min[6]), (-2.0, 2.5));
+
+        let (x, y) = (
+            [3, -1, 0, 7, i32::MIN, 5, 5, -9],
+            [2, -1, 1, 8, 0, 5, 6, -10],
+        );
+        assert_eq!(i32::max_lanes(x, y), [3, -1, 1, 8, 0, 5, 6, -9]);
+        assert_eq!(i32::le_lanes(x, y), zip_lanes(x, y, |x, y| x <= y));
+
+        // Types without an override take the scalar path.
+        let (ta, tb) = (tf([1.0; LANES]), tf([0.5; LANES]));
+        assert_eq!(TradingFloat::sub_lanes(ta, tb), tf([0.5; LANES]));
+    }
+}
@@ -0,0 +1,145 @@
+use super::{ApproxEq, One, OrderedField, Promote, Real, Semiring, Zero};
+use core::cmp::Ordering;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Mul};
+
+/// LogSpace is the log semiring: a non-negative quantity `p` stored as `ln(p)`.
+/// `a + b` is log-sum-exp and `a * b` adds the logs, so products of many small
+/// probabilities never underflow. `ln(0) = -∞` is stored as `None`, which keeps
+/// the type usable over scalars without an infinity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LogSpace<F>(Option<F>);
+
+impl<F> LogSpace<F> {
+    pub const ZERO: Self = Self(None);
+
+    pub const fn from_ln(ln: F) -> Self {
+        Self(Some(ln))
+    }
+
+    // `None` for a probability of zero
+    pub fn ln(self) -> Option<F> {
+        self.0
+    }
+}
+
+impl<F: Real> LogSpace<F> {
+    pub fn from_prob(p: F) -> Self {
+        invariant!(p >= F::zero(), "LogSpace of a negative value");
+        if p == F::zero() {
+            Self::ZERO
+        } else {
+            Self::from_ln(p.ln())
+        }
+    }
+
+    pub fn to_prob(self) -> F {
+        self.0.map_or(F::zero(), F::exp)
+    }
+}
+
+impl<F: Ord> Ord for LogSpace<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `None` (probability zero) sorts first, as `Option` does.
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<F: Ord> PartialOrd for LogSpace<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Real> Add for LogSpace<F> {
+    type Output = Self;
+    // ln(e^a + e^b) = max + ln(1 + e^(min - max)); the exponent is never positive.
+    fn add(self, rhs: Self) -> Self {
+        match (self.0, rhs.0) {
+            (Some(a), Some(b)) => {
+                let (hi, lo) = (OrderedField::max(a, b), OrderedField::min(a, b));
+                Self::from_ln(hi + (lo - hi).exp().log1p())
+            }
+            (Some(_), None) => self,
+            (None, _) => rhs,
+        }
+    }
+}
+
+impl<F: Real> Mul for LogSpace<F> {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        match (self.0, rhs.0) {
+            (Some(a), Some(b)) => Self::from_ln(a + b),
+            _ => Self::ZERO,
+        }
+    }
+}
+
+impl<F: Real> Sum for LogSpace<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<F: Real> Product for LogSpace<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<F> Zero for LogSpace<F> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F: Zero> One for LogSpace<F> {
+    #[inline]
+    fn one() -> Self {
+        Self::from_ln(F::zero())
+    }
+}
+
+impl<F: Real> Semiring for LogSpace<F> {}
+
+// Compares the logs, so `Absolute(ε)` bounds the ratio of the probabilities:
+// |ln p - ln q| <= ε means p / q lies in [e^-ε, e^ε]. `Relative` and `Ulps` apply to the
+// logs themselves, which is rarely what is meant.
+impl<F: ApproxEq> ApproxEq for LogSpace<F> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.abs_diff_eq(b, epsilon),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.relative_eq(b, max_relative),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.ulps_eq(b, max_ulps),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+}
+
+impl<F: Real> Promote<LogSpace<F>> for LogSpace<F> {
+    type Output = LogSpace<F>;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: LogSpace<F>) -> Self::Output {
+        rhs
+    }
+}
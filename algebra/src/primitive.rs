@@ -0,0 +1,610 @@
+use super::{
+    ApproxEq, ArithmeticError, CheckedArith, Decimal, Discretization, Field, NextFloat, One,
+    Promote, Rational, Ring, Semiring, TradingFloat, Zero,
+};
+
+// Algebra hierarchy for the primitive numeric types.
+// - Unsigned integers are semirings, signed integers rings, f32/f64 fields.
+//   Integer overflow follows Rust: panics in debug builds, wraps in release.
+// - f32/f64 stop at `Field`: `OrderedField` needs `Ord`, which IEEE floats do not have
+//   (use `TradingFloat` for that).
+// - bool only gets the identities: core does not define `+`/`*` on bool, so it cannot be a
+//   `Semiring` here. Logical kernels cover the boolean algebra.
+
+macro_rules! impl_identities {
+    ($zero:expr, $one:expr => $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                #[inline]
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+            impl One for $t {
+                #[inline]
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_identities!(0, 1 => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_identities!(0.0, 1.0 => f32, f64);
+impl_identities!(false, true => bool);
+
+macro_rules! impl_semiring {
+    ($($t:ty),*) => { $( impl Semiring for $t {} )* };
+}
+
+macro_rules! impl_ring {
+    ($($t:ty),*) => { $( impl Ring for $t {} )* };
+}
+
+impl_semiring!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+impl_ring!(i8, i16, i32, i64, i128, f32, f64);
+
+impl Field for f32 {
+    #[inline]
+    fn recip(self) -> Self {
+        1.0 / self
+    }
+}
+
+impl Field for f64 {
+    #[inline]
+    fn recip(self) -> Self {
+        1.0 / self
+    }
+}
+
+macro_rules! impl_float_discretization {
+    ($($t:ty),*) => {
+        $(
+            impl Discretization for $t {
+                #[inline]
+                fn floor(self) -> Self {
+                    <$t>::floor(self)
+                }
+                #[inline]
+                fn ceil(self) -> Self {
+                    <$t>::ceil(self)
+                }
+                #[inline]
+                fn round(self) -> Self {
+                    <$t>::round(self)
+                }
+            }
+        )*
+    };
+}
+
+// Integers are already discrete.
+macro_rules! impl_int_discretization {
+    ($($t:ty),*) => {
+        $(
+            impl Discretization for $t {
+                #[inline]
+                fn floor(self) -> Self {
+                    self
+                }
+                #[inline]
+                fn ceil(self) -> Self {
+                    self
+                }
+                #[inline]
+                fn round(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_float_discretization!(f32, f64);
+
+macro_rules! impl_float_next {
+    ($($t:ty),*) => {
+        $(
+            impl NextFloat for $t {
+                #[inline]
+                fn next_up(self) -> Self {
+                    <$t>::next_up(self)
+                }
+                #[inline]
+                fn next_down(self) -> Self {
+                    <$t>::next_down(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_next!(f32, f64);
+impl_int_discretization!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+macro_rules! impl_int_checked {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArith for $t {
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    <$t>::checked_add(self, rhs).ok_or(ArithmeticError::Overflow)
+                }
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    <$t>::checked_sub(self, rhs).ok_or(ArithmeticError::Overflow)
+                }
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    <$t>::checked_mul(self, rhs).ok_or(ArithmeticError::Overflow)
+                }
+                #[inline]
+                fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    if rhs == 0 {
+                        return Err(ArithmeticError::DivisionByZero);
+                    }
+                    <$t>::checked_div(self, rhs).ok_or(ArithmeticError::Overflow)
+                }
+                #[inline]
+                fn checked_rem(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    if rhs == 0 {
+                        return Err(ArithmeticError::DivisionByZero);
+                    }
+                    <$t>::checked_rem(self, rhs).ok_or(ArithmeticError::Overflow)
+                }
+                #[inline]
+                fn checked_neg(self) -> Result<Self, ArithmeticError> {
+                    <$t>::checked_neg(self).ok_or(ArithmeticError::Overflow)
+                }
+                #[inline]
+                fn checked_recip(self) -> Result<Self, ArithmeticError> {
+                    CheckedArith::checked_div(1, self)
+                }
+            }
+
+            // One ulp of an integer is one unit.
+            impl ApproxEq for $t {
+                fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    self.abs_diff(*other) as f64 <= epsilon
+                }
+
+                fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+                    let largest = (*self as f64).abs().max((*other as f64).abs());
+                    self.abs_diff(*other) as f64 <= largest * max_relative
+                }
+
+                fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+                    self.abs_diff(*other) as u128 <= max_ulps as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_int_checked!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+macro_rules! impl_float_checked {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArith for $t {
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    check_float(self + rhs)
+                }
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    check_float(self - rhs)
+                }
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    check_float(self * rhs)
+                }
+                #[inline]
+                fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    if rhs == 0.0 {
+                        return Err(ArithmeticError::DivisionByZero);
+                    }
+                    check_float(self / rhs)
+                }
+                #[inline]
+                fn checked_rem(self, rhs: Self) -> Result<Self, ArithmeticError> {
+                    if rhs == 0.0 {
+                        return Err(ArithmeticError::DivisionByZero);
+                    }
+                    check_float(self % rhs)
+                }
+                #[inline]
+                fn checked_neg(self) -> Result<Self, ArithmeticError> {
+                    check_float(-self)
+                }
+                #[inline]
+                fn checked_recip(self) -> Result<Self, ArithmeticError> {
+                    CheckedArith::checked_div(1.0, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_checked!(f32, f64);
+
+#[inline]
+fn check_float<F: Into<f64> + Copy>(result: F) -> Result<F, ArithmeticError> {
+    let wide: f64 = result.into();
+    if wide.is_finite() {
+        Ok(result)
+    } else if wide.is_nan() {
+        Err(ArithmeticError::Domain)
+    } else {
+        Err(ArithmeticError::Overflow)
+    }
+}
+
+// Maps floats onto integers whose order matches the float order, so the
+// integer distance counts representable values in between (±0 map to 0).
+macro_rules! impl_float_approx_eq {
+    ($float:ty, $bits:ty) => {
+        impl ApproxEq for $float {
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                self.is_finite() && other.is_finite() && ((*self - *other).abs() as f64) <= epsilon
+            }
+
+            fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+                if !self.is_finite() || !other.is_finite() {
+                    return false;
+                }
+                if self == other {
+                    return true;
+                }
+                let largest = self.abs().max(other.abs()) as f64;
+                ((*self - *other).abs() as f64) <= largest * max_relative
+            }
+
+            fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+                if !self.is_finite() || !other.is_finite() {
+                    return false;
+                }
+                let ordered = |x: $float| {
+                    let b = x.to_bits() as $bits;
+                    if b < 0 { <$bits>::MIN - b } else { b }
+                };
+                ordered(*self).abs_diff(ordered(*other)) as u64 <= max_ulps
+            }
+        }
+    };
+}
+
+impl_float_approx_eq!(f64, i64);
+impl_float_approx_eq!(f32, i32);
+
+// Booleans have no drift: every tolerance is exact equality.
+impl ApproxEq for bool {
+    fn abs_diff_eq(&self, other: &Self, _epsilon: f64) -> bool {
+        self == other
+    }
+
+    fn relative_eq(&self, other: &Self, _max_relative: f64) -> bool {
+        self == other
+    }
+
+    fn ulps_eq(&self, other: &Self, _max_ulps: u64) -> bool {
+        self == other
+    }
+}
+
+// Promotion lattice.
+// Every rule emits both operand orders, so `L op R` and `R op L` always agree.
+// - Same type: no-op
+// - bool is 0/1 in any numeric type
+// - Integers widen within their signedness; a signed type absorbs narrower unsigned ones
+// - Mixed signedness without a containing type widens to the next signed type
+//   (u128 with any signed type has none, and falls back to f64)
+// - Integers + float -> that float (i32 + f32 -> f32), f32 + f64 -> f64
+// - TradingFloat absorbs every primitive (NaN/Inf f32/f64 inputs panic)
+
+macro_rules! promote_self {
+    ($($t:ty),*) => {
+        $(
+            impl Promote<$t> for $t {
+                type Output = $t;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $t) -> Self::Output {
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+// `$wide` absorbs each listed type.
+macro_rules! promote_into {
+    ($wide:ty => $($narrow:ty),*) => {
+        $(
+            impl Promote<$narrow> for $wide {
+                type Output = $wide;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $narrow) -> Self::Output {
+                    rhs as $wide
+                }
+            }
+
+            impl Promote<$wide> for $narrow {
+                type Output = $wide;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self as $wide
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $wide) -> Self::Output {
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+// Neither side contains the other: both convert to `$out`.
+macro_rules! promote_join {
+    ($($a:ty, $b:ty => $out:ty);* $(;)?) => {
+        $(
+            impl Promote<$b> for $a {
+                type Output = $out;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self as $out
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $b) -> Self::Output {
+                    rhs as $out
+                }
+            }
+
+            impl Promote<$a> for $b {
+                type Output = $out;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self as $out
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $a) -> Self::Output {
+                    rhs as $out
+                }
+            }
+        )*
+    };
+}
+
+// bool converts through u8, since `bool as f32` is not a valid cast.
+macro_rules! promote_bool {
+    ($($t:ty),*) => {
+        $(
+            impl Promote<bool> for $t {
+                type Output = $t;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: bool) -> Self::Output {
+                    rhs as u8 as $t
+                }
+            }
+
+            impl Promote<$t> for bool {
+                type Output = $t;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self as u8 as $t
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $t) -> Self::Output {
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! promote_trading_float {
+    ($($t:ty),*) => {
+        $(
+            impl Promote<$t> for TradingFloat {
+                type Output = TradingFloat;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $t) -> Self::Output {
+                    TradingFloat::new(rhs as f64)
+                }
+            }
+
+            impl Promote<TradingFloat> for $t {
+                type Output = TradingFloat;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    TradingFloat::new(self as f64)
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: TradingFloat) -> Self::Output {
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+// Volume times price: integers enter the exact Decimal world (panics if out of range).
+macro_rules! promote_decimal {
+    ($($t:ty),*) => {
+        $(
+            impl<const SCALE: u32> Promote<$t> for Decimal<SCALE> {
+                type Output = Decimal<SCALE>;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $t) -> Self::Output {
+                    Decimal::from_i128(i128::from(rhs))
+                }
+            }
+
+            impl<const SCALE: u32> Promote<Decimal<SCALE>> for $t {
+                type Output = Decimal<SCALE>;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    Decimal::from_i128(i128::from(self))
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: Decimal<SCALE>) -> Self::Output {
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! promote_rational {
+    ($($t:ty),*) => {
+        $(
+            impl Promote<$t> for Rational {
+                type Output = Rational;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    self
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: $t) -> Self::Output {
+                    Rational::from_int(rhs as i128)
+                }
+            }
+
+            impl Promote<Rational> for $t {
+                type Output = Rational;
+
+                #[inline(always)]
+                fn promote_left(self) -> Self::Output {
+                    Rational::from_int(self as i128)
+                }
+
+                #[inline(always)]
+                fn promote_right(rhs: Rational) -> Self::Output {
+                    rhs
+                }
+            }
+        )*
+    };
+}
+
+promote_self!(
+    bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64
+);
+promote_bool!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+promote_into!(u16 => u8);
+promote_into!(u32 => u8, u16);
+promote_into!(u64 => u8, u16, u32);
+promote_into!(u128 => u8, u16, u32, u64);
+
+promote_into!(i16 => i8, u8);
+promote_into!(i32 => i8, i16, u8, u16);
+promote_into!(i64 => i8, i16, i32, u8, u16, u32);
+promote_into!(i128 => i8, i16, i32, i64, u8, u16, u32, u64);
+
+promote_join! {
+    u8, i8 => i16;
+    u16, i8 => i32;
+    u16, i16 => i32;
+    u32, i8 => i64;
+    u32, i16 => i64;
+    u32, i32 => i64;
+    u64, i8 => i128;
+    u64, i16 => i128;
+    u64, i32 => i128;
+    u64, i64 => i128;
+    u128, i8 => f64;
+    u128, i16 => f64;
+    u128, i32 => f64;
+    u128, i64 => f64;
+    u128, i128 => f64;
+}
+
+promote_into!(f32 => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+promote_into!(f64 => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32);
+
+promote_trading_float!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+promote_decimal!(u8, u16, u32, u64, i8, i16, i32, i64);
+promote_rational!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddKernel, BinaryKernel, Line, MulKernel};
+
+    fn add<L: Promote<R>, R>(l: L, r: R) -> L::Output
+    where
+        L::Output: Semiring + Line,
+    {
+        AddKernel.apply(l, r)
+    }
+
+    #[test]
+    fn test_promotion_lattice() {
+        let x: f32 = add(2i32, 0.5f32);
+        assert_eq!(x, 2.5);
+        let n: i64 = add(true, 41i64);
+        assert_eq!(n, 42);
+        let m: i16 = add(200u8, -1i8);
+        assert_eq!(m, 199);
+        let w: i128 = add(-1i64, u64::MAX);
+        assert_eq!(w, u64::MAX as i128 - 1);
+        let f: f64 = add(1u128, 1i32);
+        assert_eq!(f, 2.0);
+        assert_eq!(add(3u32, TradingFloat::new(0.5)), TradingFloat::new(3.5));
+
+        let notional: Decimal<2> = MulKernel.apply(3u32, "101.25".parse::<Decimal<2>>().unwrap());
+        assert_eq!(notional.to_string(), "303.75");
+
+        // Integers widen exactly; a mantissa that cannot hold them panics rather than wraps.
+        let volume: Decimal<2> = add(u64::MAX, Decimal::<2>::ZERO);
+        assert_eq!(volume.mantissa(), u64::MAX as i128 * 100);
+        let narrow = std::panic::catch_unwind(|| add(Decimal::<30>::ZERO, u64::MAX));
+        assert!(narrow.is_err());
+    }
+}
@@ -0,0 +1,551 @@
+use super::Real;
+use core::fmt::{self, Debug, Display};
+use std::sync::Arc;
+
+/// A low-discrepancy point set addressed by index, like `CounterRng`: point `index` can be
+/// produced without the ones before it, so batches and chunks never overlap or shift.
+pub trait LowDiscrepancy: Clone + Debug + Send + Sync + 'static {
+    fn dims(&self) -> usize;
+    // Coordinate `dim` of point `index`, strictly inside (0, 1)
+    fn coordinate(&self, index: u64, dim: usize) -> f64;
+}
+
+// splitmix64 finalizer; decorrelates per-dimension and per-digit scrambling seeds.
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// Primitive polynomial degree `s`, its interior coefficients `a` and the initial direction
+// numbers m_1..m_s, for dimensions 2..=32 of new-joe-kuo-6.21201 (Joe & Kuo, 2008).
+// Dimension 1 is the van der Corput sequence and has no entry.
+#[rustfmt::skip]
+const JOE_KUO: [(u32, u32, &[u32]); 31] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+    (7, 7, &[1, 1, 3, 13, 7, 35, 63]),
+    (7, 8, &[1, 3, 5, 9, 1, 25, 53]),
+    (7, 14, &[1, 3, 1, 13, 9, 35, 107]),
+    (7, 19, &[1, 3, 1, 5, 27, 61, 31]),
+    (7, 21, &[1, 1, 5, 11, 19, 41, 61]),
+    (7, 28, &[1, 3, 5, 3, 3, 13, 69]),
+    (7, 31, &[1, 1, 7, 13, 1, 19, 1]),
+    (7, 32, &[1, 3, 7, 5, 13, 19, 59]),
+    (7, 37, &[1, 1, 3, 9, 25, 29, 41]),
+    (7, 41, &[1, 3, 5, 13, 23, 1, 55]),
+    (7, 42, &[1, 3, 7, 3, 13, 59, 17]),
+];
+
+const SOBOL_BITS: usize = 32;
+
+// Every primitive polynomial over GF(2) by degree, then by its interior coefficients `a`:
+// the order of the Joe-Kuo table, which lists the first 31 of them. `x` has order
+// 2^s - 1 modulo a primitive polynomial of degree `s`, and a smaller order otherwise.
+fn primitive_polynomials() -> impl Iterator<Item = (u32, u32)> {
+    (1u32..).flat_map(|s| {
+        let order = (1u64 << s) - 1;
+        (0..1u32 << (s - 1))
+            .filter(move |&a| {
+                let poly = (1 << s) | (a << 1) | 1;
+                let is_one = |e: u64| x_pow_mod(e, poly, s) == 1;
+                is_one(order) && prime_factors(order).all(|q| !is_one(order / q))
+            })
+            .map(move |a| (s, a))
+    })
+}
+
+// x^e modulo `poly` of degree `s`, all in GF(2)[x]
+fn x_pow_mod(mut e: u64, poly: u32, s: u32) -> u32 {
+    // Horner over the bits of `r`, reducing each shift by `poly`
+    let mul = |l: u32, r: u32| {
+        (0..s).rev().fold(0, |acc, k| {
+            let acc = if (acc << 1) >> s & 1 == 1 {
+                (acc << 1) ^ poly
+            } else {
+                acc << 1
+            };
+            if r >> k & 1 == 1 { acc ^ l } else { acc }
+        })
+    };
+    // x itself, already reduced unless `poly` is x + 1
+    let (mut acc, mut base) = (1, if s == 1 { 1 } else { 2 });
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = mul(acc, base);
+        }
+        base = mul(base, base);
+        e >>= 1;
+    }
+    acc
+}
+
+fn prime_factors(mut n: u64) -> impl Iterator<Item = u64> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            factors.push(p);
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors.into_iter()
+}
+
+// Direction numbers v_1..v_32 of one dimension from its polynomial and initial m_1..m_s.
+fn direction_numbers(s: u32, a: u32, m: &[u32]) -> [u32; SOBOL_BITS] {
+    let s = s as usize;
+    let mut v = [0u32; SOBOL_BITS];
+    for k in 0..SOBOL_BITS {
+        v[k] = if k < s {
+            m[k] << (31 - k)
+        } else {
+            // v_k = a_1 v_{k-1} ^ ... ^ a_{s-1} v_{k-s+1} ^ v_{k-s} ^ (v_{k-s} >> s)
+            (1..s)
+                .filter(|&j| (a >> (s - 1 - j)) & 1 == 1)
+                .fold(v[k - s] ^ (v[k - s] >> s), |acc, j| acc ^ v[k - j])
+        };
+    }
+    v
+}
+
+/// Sobol sequence with Joe-Kuo direction numbers, in Gray-code order, up to 2³² points.
+/// Optionally Owen-scrambled, which keeps the net structure and makes the estimator
+/// unbiased, so independent seeds give a confidence interval.
+///
+/// Dimensions 2..=32 use the initial direction numbers of new-joe-kuo-6.21201. Up to
+/// `MAX_DIMS` = 1024 the polynomials continue in the same order, generated rather than
+/// embedded, with hashed initial numbers: every coordinate stays a (0, m, 1)-net, but
+/// pairs of them lack Joe and Kuo's two-dimensional optimisation. The Brownian bridge puts
+/// the coarse path structure in the first dimensions, which keep the tuned numbers. `new`
+/// reports larger requests as a `SobolDimensionError`; use `Halton` or pseudo-random
+/// shocks beyond that.
+#[derive(Debug, Clone)]
+pub struct Sobol {
+    directions: Arc<Vec<[u32; SOBOL_BITS]>>,
+    scramble: Option<u64>,
+}
+
+/// `Sobol::new` was asked for a dimension count outside `1..=Sobol::MAX_DIMS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SobolDimensionError {
+    pub requested: usize,
+}
+
+impl Display for SobolDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sobol: {} dimensions requested, direction numbers cover 1..={}",
+            self.requested,
+            Sobol::MAX_DIMS
+        )
+    }
+}
+
+impl std::error::Error for SobolDimensionError {}
+
+impl Sobol {
+    // Enough for a daily four-year path through the Brownian bridge
+    pub const MAX_DIMS: usize = 1024;
+
+    pub fn new(dims: usize) -> Result<Self, SobolDimensionError> {
+        if !(1..=Self::MAX_DIMS).contains(&dims) {
+            return Err(SobolDimensionError { requested: dims });
+        }
+        let mut directions = vec![core::array::from_fn(|k| 1 << (31 - k))];
+        // Past the table, any odd m_k < 2^k gives a valid sequence; hash them per row.
+        let generated =
+            primitive_polynomials()
+                .enumerate()
+                .skip(JOE_KUO.len())
+                .map(|(row, (s, a))| {
+                    let m = (0..s)
+                        .map(|k| (mix64((row as u64) << 8 | k as u64) as u32 & ((2 << k) - 1)) | 1)
+                        .collect();
+                    (s, a, m)
+                });
+        let rows = JOE_KUO
+            .iter()
+            .map(|&(s, a, m)| (s, a, m.to_vec()))
+            .chain(generated);
+        for (s, a, m) in rows.take(dims - 1) {
+            directions.push(direction_numbers(s, a, &m));
+        }
+        Ok(Self {
+            directions: Arc::new(directions),
+            scramble: None,
+        })
+    }
+
+    pub fn scrambled(mut self, seed: u64) -> Self {
+        self.scramble = Some(seed);
+        self
+    }
+
+    // The raw 32-bit coordinate: the xor of the direction numbers selected by the Gray
+    // code of `index`.
+    pub fn bits(&self, index: u64, dim: usize) -> u32 {
+        assert!(
+            index < 1 << SOBOL_BITS,
+            "sobol: index {} out of range",
+            index
+        );
+        let gray = index ^ (index >> 1);
+        let v = &self.directions[dim];
+        let x = (0..SOBOL_BITS)
+            .filter(|&k| (gray >> k) & 1 == 1)
+            .fold(0, |acc, k| acc ^ v[k]);
+        match self.scramble {
+            Some(seed) => owen_scramble(x, mix64(seed ^ mix64(dim as u64)) as u32),
+            None => x,
+        }
+    }
+}
+
+// Nested uniform scrambling in base 2 (Burley, "Practical Hash-based Owen Scrambling",
+// 2020). On the bit-reversed value every step only lets lower bits change higher ones, so
+// each digit is flipped by a hash of the digits above it.
+fn owen_scramble(x: u32, seed: u32) -> u32 {
+    let mut x = x.reverse_bits();
+    x ^= x.wrapping_mul(0x3d20_adea);
+    x = x.wrapping_add(seed);
+    x = x.wrapping_mul((seed >> 16) | 1);
+    x ^= x.wrapping_mul(0x0552_6c56);
+    x ^= x.wrapping_mul(0x53a2_2864);
+    x.reverse_bits()
+}
+
+impl LowDiscrepancy for Sobol {
+    fn dims(&self) -> usize {
+        self.directions.len()
+    }
+
+    fn coordinate(&self, index: u64, dim: usize) -> f64 {
+        // The centre of the 2⁻³² cell, so the origin maps inside (0, 1) as well.
+        (self.bits(index, dim) as f64 + 0.5) / (1u64 << SOBOL_BITS) as f64
+    }
+}
+
+/// Halton sequence: dimension `d` is the radical inverse in the `d`-th prime base,
+/// starting at n = 1. Cheap for any number of dimensions, but high bases correlate
+/// badly without scrambling.
+#[derive(Debug, Clone)]
+pub struct Halton {
+    bases: Arc<Vec<u64>>,
+    scramble: Option<u64>,
+}
+
+impl Halton {
+    pub fn new(dims: usize) -> Self {
+        assert!(dims > 0, "halton: no dimensions");
+        let mut bases = Vec::with_capacity(dims);
+        let mut candidate = 2u64;
+        while bases.len() < dims {
+            if bases.iter().all(|&p| !candidate.is_multiple_of(p)) {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+        Self {
+            bases: Arc::new(bases),
+            scramble: None,
+        }
+    }
+
+    // Owen scrambling restricted to random digit shifts: the shift of every digit hashes
+    // the digits above it, which keeps the nested structure in each base.
+    pub fn scrambled(mut self, seed: u64) -> Self {
+        self.scramble = Some(seed);
+        self
+    }
+}
+
+impl LowDiscrepancy for Halton {
+    fn dims(&self) -> usize {
+        self.bases.len()
+    }
+
+    fn coordinate(&self, index: u64, dim: usize) -> f64 {
+        let base = self.bases[dim];
+        let inv = 1.0 / base as f64;
+        let (mut n, mut scale, mut x) = (index + 1, inv, 0.0);
+        match self.scramble {
+            None => {
+                while n > 0 {
+                    x += (n % base) as f64 * scale;
+                    n /= base;
+                    scale *= inv;
+                }
+            }
+            Some(seed) => {
+                // Scrambled digits never run out; stop at the resolution of an f64.
+                let mut node = mix64(seed ^ mix64(dim as u64));
+                while scale > f64::EPSILON {
+                    let digit = n % base;
+                    x += ((digit + mix64(node) % base) % base) as f64 * scale;
+                    node = mix64(node ^ (digit + 1));
+                    n /= base;
+                    scale *= inv;
+                }
+            }
+        }
+        x
+    }
+}
+
+/// Brownian-bridge construction over a time grid `0 < t_1 < ... < t_n`.
+/// Maps n independent standard normals to the n increments W(t_k) - W(t_{k-1}): the first
+/// normal fixes W(t_n), the next the midpoint, and so on by bisection. The leading
+/// dimensions of a QMC point, which are the best distributed, then carry most of the
+/// path's variance.
+#[derive(Debug, Clone)]
+pub struct BrownianBridge<F> {
+    plan: Arc<Vec<BridgeStep<F>>>,
+}
+
+// Fill W[target] from W[left] (W(0) = 0 when `None`) and W[right].
+#[derive(Debug, Clone, Copy)]
+struct BridgeStep<F> {
+    target: usize,
+    left: Option<usize>,
+    right: usize,
+    left_weight: F,
+    right_weight: F,
+    std: F,
+}
+
+impl<F: Real> BrownianBridge<F> {
+    pub fn new(times: &[F]) -> Self {
+        let n = times.len();
+        assert!(n > 0, "brownian bridge: empty time grid");
+        assert!(
+            times[0] > F::zero() && times.windows(2).all(|w| w[0] < w[1]),
+            "brownian bridge: times must be positive and strictly increasing"
+        );
+        let t = |i: Option<usize>| i.map_or(F::zero(), |i| times[i]);
+
+        let mut filled = vec![false; n];
+        filled[n - 1] = true;
+        let mut plan = vec![BridgeStep {
+            target: n - 1,
+            left: None,
+            right: n - 1,
+            left_weight: F::zero(),
+            right_weight: F::zero(),
+            std: times[n - 1].sqrt(),
+        }];
+        let mut j = 0;
+        for _ in 1..n {
+            // Next gap: j..k unfilled, bounded by k on the right and j - 1 (or 0) on the left
+            while filled[j] {
+                j += 1;
+            }
+            let mut k = j;
+            while !filled[k] {
+                k += 1;
+            }
+            let target = j + (k - 1 - j) / 2;
+            filled[target] = true;
+
+            let left = j.checked_sub(1);
+            let (tl, tm, tr) = (t(left), times[target], times[k]);
+            plan.push(BridgeStep {
+                target,
+                left,
+                right: k,
+                left_weight: (tr - tm) / (tr - tl),
+                right_weight: (tm - tl) / (tr - tl),
+                std: ((tm - tl) * (tr - tm) / (tr - tl)).sqrt(),
+            });
+            j = if k + 1 >= n { 0 } else { k + 1 };
+        }
+        Self {
+            plan: Arc::new(plan),
+        }
+    }
+
+    // `steps` equal steps up to `horizon`
+    pub fn uniform(steps: usize, horizon: F) -> Self {
+        let dt = horizon / F::from_f64(steps as f64);
+        let times: Vec<F> = (1..=steps).map(|k| dt * F::from_f64(k as f64)).collect();
+        Self::new(&times)
+    }
+
+    pub fn steps(&self) -> usize {
+        self.plan.len()
+    }
+
+    // One path: `normals` in bridge order in, increments in time order out.
+    pub fn increments(&self, normals: &[F], out: &mut [F]) {
+        let n = self.steps();
+        invariant!(
+            normals.len() == n && out.len() == n,
+            "brownian bridge: expected {} steps",
+            n
+        );
+        // `out` holds the levels W(t_k) until the final differencing pass.
+        for (step, &z) in self.plan.iter().zip(normals) {
+            let left = step.left.map_or(F::zero(), |l| out[l]);
+            out[step.target] =
+                step.left_weight * left + step.right_weight * out[step.right] + step.std * z;
+        }
+        for k in (1..n).rev() {
+            out[k] = out[k] - out[k - 1];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TradingFloat;
+
+    // Every elementary box of volume 2^-m in dims (a, b) holds exactly one of the first 2^m
+    // points: the (0, m, 2)-net property.
+    fn is_net(seq: &Sobol, (a, b): (usize, usize), m: u32) -> bool {
+        (0..=m).all(|split| {
+            let mut seen = vec![false; 1 << m];
+            (0..1u64 << m).all(|i| {
+                let top = |bits: u32, n: u32| bits.checked_shr(32 - n).unwrap_or(0) as usize;
+                let (x, y) = (top(seq.bits(i, a), split), top(seq.bits(i, b), m - split));
+                let cell = (x << (m - split)) | y;
+                !std::mem::replace(&mut seen[cell], true)
+            })
+        })
+    }
+
+    #[test]
+    fn test_low_discrepancy_sequences() {
+        // Table rows are valid: odd initial direction numbers m_k < 2^k.
+        for (s, _, m) in JOE_KUO {
+            assert_eq!(m.len(), s as usize);
+            assert!(
+                m.iter()
+                    .enumerate()
+                    .all(|(k, &mk)| mk % 2 == 1 && mk < 2 << k)
+            );
+        }
+
+        // The generated polynomials continue the table's order: 480 up to degree 12.
+        assert!(
+            primitive_polynomials()
+                .zip(JOE_KUO)
+                .all(|(poly, (s, a, _))| poly == (s, a))
+        );
+        let low = primitive_polynomials().take_while(|&(s, _)| s <= 12);
+        assert_eq!(low.count(), 480);
+
+        assert_eq!(
+            Sobol::new(Sobol::MAX_DIMS + 1).unwrap_err(),
+            SobolDimensionError { requested: 1025 }
+        );
+        assert!(Sobol::new(0).is_err());
+        let sobol = Sobol::new(Sobol::MAX_DIMS).unwrap();
+        // Past 2^32 points the Gray code would wrap onto earlier ones.
+        assert!(std::panic::catch_unwind(|| sobol.bits(1 << 32, 0)).is_err());
+        // Generated dimensions are still one point per cell of width 2^-10.
+        for dim in [32, 500, 1023] {
+            let mut cells: Vec<_> = (0..1024).map(|i| sobol.bits(i, dim) >> 22).collect();
+            cells.sort_unstable();
+            assert!(cells.iter().copied().eq(0..1024));
+        }
+        let first = |dim| (0..4).map(|i| sobol.bits(i, dim) >> 30).collect::<Vec<_>>();
+        // In units of 1/4: 0, 1/2, 3/4, 1/4 and 0, 1/2, 1/4, 3/4
+        assert_eq!(first(0), [0, 2, 3, 1]);
+        assert_eq!(first(1), [0, 2, 1, 3]);
+        assert!(is_net(&sobol, (0, 1), 10));
+        // Scrambling keeps the structure but moves every point.
+        let scrambled = sobol.clone().scrambled(17);
+        assert!(is_net(&scrambled, (0, 1), 10));
+        assert_ne!(scrambled.bits(5, 3), sobol.bits(5, 3));
+
+        // ∫ Π_d 2 x_d over [0, 1]^8 = 1; plain Monte Carlo with 4096 points is off by ~0.05.
+        let n = 4096;
+        let mean = (0..n)
+            .map(|i| {
+                (0..8)
+                    .map(|d| 2.0 * scrambled.coordinate(i, d))
+                    .product::<f64>()
+            })
+            .sum::<f64>()
+            / n as f64;
+        assert!((mean - 1.0).abs() < 0.01, "{mean}");
+
+        let halton = Halton::new(3);
+        let units = |dim, base: f64| {
+            (0..6)
+                .map(|i| (halton.coordinate(i, dim) * base.powi(3)).round())
+                .collect::<Vec<_>>()
+        };
+        // 1/2, 1/4, 3/4, 1/8, 5/8, 3/8 and 1/3, 2/3, 1/9, 4/9, 7/9, 2/9
+        assert_eq!(units(0, 2.0), [4.0, 2.0, 6.0, 1.0, 5.0, 3.0]);
+        assert_eq!(units(1, 3.0), [9.0, 18.0, 3.0, 12.0, 21.0, 6.0]);
+        let scrambled = Halton::new(3).scrambled(4);
+        assert!((0..1000).all(|i| (0..3).all(|d| {
+            let x = scrambled.coordinate(i, d);
+            0.0 < x && x < 1.0
+        })));
+    }
+
+    #[test]
+    fn test_brownian_bridge() {
+        let t = TradingFloat::new;
+        let times = [t(0.25), t(0.5), t(1.0), t(2.0), t(3.0)];
+        let bridge = BrownianBridge::new(&times);
+        let order: Vec<usize> = bridge.plan.iter().map(|s| s.target).collect();
+        assert_eq!(order, [4, 1, 0, 2, 3]);
+
+        // The first normal alone sets the terminal value W(T) = √T z_0.
+        let normals = [t(1.5), t(-0.3), t(0.8), t(0.1), t(-1.2)];
+        let mut out = [t(0.0); 5];
+        bridge.increments(&normals, &mut out);
+        let terminal = out.iter().fold(t(0.0), |acc, &x| acc + x);
+        assert!((terminal - t(3.0).sqrt() * t(1.5)).to_f64().abs() < 1e-15);
+
+        // Each normal alone: the increments are linear in the normals, and summing the
+        // outer products over the unit vectors gives the covariance diag(Δt).
+        let mut cov = [[0.0; 5]; 5];
+        for i in 0..5 {
+            let mut e = [t(0.0); 5];
+            e[i] = t(1.0);
+            bridge.increments(&e, &mut out);
+            for a in 0..5 {
+                for b in 0..5 {
+                    cov[a][b] += (out[a] * out[b]).to_f64();
+                }
+            }
+        }
+        let dt = [0.25, 0.25, 0.5, 1.0, 1.0];
+        for (a, row) in cov.iter().enumerate() {
+            for (b, &c) in row.iter().enumerate() {
+                let expected = if a == b { dt[a] } else { 0.0 };
+                assert!((c - expected).abs() < 1e-14, "{a} {b} {c}");
+            }
+        }
+    }
+}
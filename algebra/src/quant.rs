@@ -0,0 +1,117 @@
+use super::Data;
+
+// Integer storage for affine quantization. Arithmetic happens in i32.
+pub trait Quantized: Data {
+    const MIN: i32;
+    const MAX: i32;
+
+    fn from_i32_saturating(x: i32) -> Self;
+    fn to_i32(self) -> i32;
+}
+
+macro_rules! impl_quantized {
+    ($($t:ty),*) => {
+        $(
+            impl Quantized for $t {
+                const MIN: i32 = <$t>::MIN as i32;
+                const MAX: i32 = <$t>::MAX as i32;
+
+                #[inline(always)]
+                fn from_i32_saturating(x: i32) -> Self {
+                    x.clamp(<Self as Quantized>::MIN, <Self as Quantized>::MAX) as $t
+                }
+
+                #[inline(always)]
+                fn to_i32(self) -> i32 {
+                    self as i32
+                }
+            }
+        )*
+    };
+}
+
+impl_quantized!(i8, u8);
+
+/// Affine quantization parameters: `real = scale * (q - zero_point)`.
+/// The zero point is the integer that represents 0.0 exactly, so zero padding stays exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuantParams {
+    pub scale: f32,
+    pub zero_point: i32,
+}
+
+impl QuantParams {
+    pub fn new(scale: f32, zero_point: i32) -> Self {
+        invariant!(
+            scale.is_finite() && scale > 0.0,
+            "quantization scale must be positive and finite: {}",
+            scale
+        );
+        Self { scale, zero_point }
+    }
+
+    // Maps [min, max] onto the full integer range. The range is widened to contain 0.
+    pub fn from_range<Q: Quantized>(min: f32, max: f32) -> Self {
+        let (min, max) = (min.min(0.0), max.max(0.0));
+        let steps = (Q::MAX - Q::MIN) as f32;
+        let scale = if max > min { (max - min) / steps } else { 1.0 };
+        let zero_point = (Q::MIN as f32 - min / scale).round() as i32;
+        Self::new(scale, zero_point.clamp(Q::MIN, Q::MAX))
+    }
+
+    // Symmetric around zero, e.g. [-127, 127] for i8 and [1, 255] around 128 for u8.
+    // The usual choice for weights: the zero point drops out of the products.
+    pub fn symmetric<Q: Quantized>(absmax: f32) -> Self {
+        let zero_point = (Q::MIN + Q::MAX + 1) / 2;
+        let half_range = (Q::MAX - zero_point) as f32;
+        let scale = if absmax > 0.0 {
+            absmax / half_range
+        } else {
+            1.0
+        };
+        Self::new(scale, zero_point)
+    }
+
+    // Round to nearest, saturating at the ends of the integer range.
+    #[inline(always)]
+    pub fn quantize<Q: Quantized>(self, x: f32) -> Q {
+        Q::from_i32_saturating(((x / self.scale).round() as i32).saturating_add(self.zero_point))
+    }
+
+    #[inline(always)]
+    pub fn dequantize<Q: Quantized>(self, q: Q) -> f32 {
+        self.scale * (q.to_i32() - self.zero_point) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quant_params() {
+        let p = QuantParams::from_range::<u8>(-1.0, 3.0);
+        assert_eq!(p.zero_point, 64);
+        assert_eq!(p.quantize::<u8>(0.0), 64);
+        assert_eq!(p.dequantize(p.quantize::<u8>(0.0)), 0.0);
+        // Saturates instead of wrapping.
+        assert_eq!(p.quantize::<u8>(10.0), 255);
+        assert_eq!(p.quantize::<u8>(-10.0), 0);
+        // Half a step of error inside the range
+        for x in [-1.0, -0.3, 0.01, 1.7, 3.0] {
+            assert!((p.dequantize(p.quantize::<u8>(x)) - x).abs() <= p.scale / 2.0);
+        }
+
+        // Ranges that exclude zero are widened to contain it.
+        let positive = QuantParams::from_range::<i8>(2.0, 4.0);
+        assert_eq!(positive.zero_point, -128);
+        assert_eq!(positive.quantize::<i8>(4.0), 127);
+
+        let w = QuantParams::symmetric::<i8>(0.5);
+        assert_eq!(w.zero_point, 0);
+        assert_eq!(w.quantize::<i8>(-0.5), -127);
+        assert_eq!(w.quantize::<i8>(0.5), 127);
+        let w = QuantParams::symmetric::<u8>(0.5);
+        assert_eq!((w.quantize::<u8>(-0.5), w.quantize::<u8>(0.5)), (1, 255));
+    }
+}
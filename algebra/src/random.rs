@@ -0,0 +1,366 @@
+use super::{Data, Real};
+use core::fmt::Debug;
+use std::sync::Arc;
+
+/// Counter-based generator: `block(counter)` is a pure function of (seed, stream, counter).
+/// Every sample is addressed by its index instead of drawn from mutable state, so results
+/// are bit-identical however the work is split across chunks or threads.
+pub trait CounterRng: Copy + Clone + Debug + Send + Sync + 'static {
+    fn new(seed: u64, stream: u64) -> Self;
+    // 128 random bits for one counter value
+    fn block(&self, counter: u64) -> [u64; 2];
+}
+
+const PHILOX_M: [u32; 2] = [0xD251_1F53, 0xCD9E_8D57];
+const PHILOX_W: [u32; 2] = [0x9E37_79B9, 0xBB67_AE85];
+
+// Philox4x32-10 (Salmon et al., "Parallel Random Numbers: As Easy as 1, 2, 3", SC'11)
+pub fn philox4x32_10(ctr: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+    let mulhilo = |a: u32, b: u32| {
+        let p = a as u64 * b as u64;
+        ((p >> 32) as u32, p as u32)
+    };
+    let (mut c, mut k) = (ctr, key);
+    for round in 0..10 {
+        if round > 0 {
+            k = [
+                k[0].wrapping_add(PHILOX_W[0]),
+                k[1].wrapping_add(PHILOX_W[1]),
+            ];
+        }
+        let (hi0, lo0) = mulhilo(PHILOX_M[0], c[0]);
+        let (hi1, lo1) = mulhilo(PHILOX_M[1], c[2]);
+        c = [hi1 ^ c[1] ^ k[0], lo1, hi0 ^ c[3] ^ k[1], lo0];
+    }
+    c
+}
+
+const THREEFRY_ROTATIONS: [u32; 8] = [16, 42, 12, 31, 16, 32, 24, 21];
+const THREEFRY_PARITY: u64 = 0x1BD1_1BDA_A9FC_1A22;
+
+// Threefry2x64-20, from the same paper; only adds, rotations and xors.
+pub fn threefry2x64_20(ctr: [u64; 2], key: [u64; 2]) -> [u64; 2] {
+    let ks = [key[0], key[1], THREEFRY_PARITY ^ key[0] ^ key[1]];
+    let mut x = [ctr[0].wrapping_add(ks[0]), ctr[1].wrapping_add(ks[1])];
+    for round in 0..20 {
+        x[0] = x[0].wrapping_add(x[1]);
+        x[1] = x[1].rotate_left(THREEFRY_ROTATIONS[round % 8]) ^ x[0];
+        if round % 4 == 3 {
+            let s = round / 4 + 1;
+            x[0] = x[0].wrapping_add(ks[s % 3]);
+            x[1] = x[1].wrapping_add(ks[(s + 1) % 3]).wrapping_add(s as u64);
+        }
+    }
+    x
+}
+
+/// Philox4x32-10 keyed by the seed, with the stream in the upper half of the counter.
+/// The default choice: ten rounds of 32-bit multiplies, fast on CPUs and GPUs alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Philox {
+    key: [u32; 2],
+    stream: [u32; 2],
+}
+
+impl CounterRng for Philox {
+    fn new(seed: u64, stream: u64) -> Self {
+        Self {
+            key: [seed as u32, (seed >> 32) as u32],
+            stream: [stream as u32, (stream >> 32) as u32],
+        }
+    }
+
+    #[inline]
+    fn block(&self, counter: u64) -> [u64; 2] {
+        let ctr = [
+            counter as u32,
+            (counter >> 32) as u32,
+            self.stream[0],
+            self.stream[1],
+        ];
+        let [a, b, c, d] = philox4x32_10(ctr, self.key);
+        [(b as u64) << 32 | a as u64, (d as u64) << 32 | c as u64]
+    }
+}
+
+/// Threefry2x64-20 with the same (seed, stream) layout; no multiplies, for targets where
+/// 32-bit `mulhi` is slow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threefry {
+    seed: u64,
+    stream: u64,
+}
+
+impl CounterRng for Threefry {
+    fn new(seed: u64, stream: u64) -> Self {
+        Self { seed, stream }
+    }
+
+    #[inline]
+    fn block(&self, counter: u64) -> [u64; 2] {
+        threefry2x64_20([counter, self.stream], [self.seed, 0])
+    }
+}
+
+// Sample `index` takes 64 bits: half of block `index / 2`.
+#[inline]
+fn word<G: CounterRng>(rng: &G, index: u64) -> u64 {
+    rng.block(index / 2)[(index % 2) as usize]
+}
+
+// Top 53 bits as a double in [0, 1)
+#[inline]
+fn unit(bits: u64) -> f64 {
+    (bits >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// A distribution sampled by index, so any element of a random tensor can be produced
+/// independently of the others.
+pub trait Distribution: Clone + Debug + Send + Sync + 'static {
+    type Output: Data;
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> Self::Output;
+}
+
+/// U[low, high)
+#[derive(Debug, Clone, Copy)]
+pub struct Uniform<F> {
+    pub low: F,
+    pub high: F,
+}
+
+impl<F: Real> Uniform<F> {
+    pub fn new(low: F, high: F) -> Self {
+        assert!(low < high, "uniform: [{low:?}, {high:?}) is empty");
+        Self { low, high }
+    }
+
+    pub fn standard() -> Self {
+        Self::new(F::zero(), F::one())
+    }
+}
+
+impl<F: Real> Distribution for Uniform<F> {
+    type Output = F;
+
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> F {
+        let u = F::from_f64(unit(word(rng, index)));
+        self.low + (self.high - self.low) * u
+    }
+}
+
+/// N(mean, std²) by Box-Muller: the two halves of one block give a cosine and a sine
+/// sample. Ziggurat is faster on average, but its rejection loop consumes a variable
+/// number of words, which breaks the one-index-one-counter addressing.
+#[derive(Debug, Clone, Copy)]
+pub struct Normal<F> {
+    pub mean: F,
+    pub std: F,
+}
+
+impl<F: Real> Normal<F> {
+    pub fn new(mean: F, std: F) -> Self {
+        assert!(
+            std > F::zero(),
+            "normal: standard deviation {std:?} is not positive"
+        );
+        Self { mean, std }
+    }
+
+    pub fn standard() -> Self {
+        Self::new(F::zero(), F::one())
+    }
+}
+
+impl<F: Real> Distribution for Normal<F> {
+    type Output = F;
+
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> F {
+        let [a, b] = rng.block(index / 2);
+        // 1 - u is in (0, 1], so the log is finite.
+        let radius = (-2.0 * (1.0 - unit(a)).ln()).sqrt();
+        let angle = core::f64::consts::TAU * unit(b);
+        let z = if index.is_multiple_of(2) {
+            radius * angle.cos()
+        } else {
+            radius * angle.sin()
+        };
+        self.mean + self.std * F::from_f64(z)
+    }
+}
+
+/// `true` with probability `p`
+#[derive(Debug, Clone, Copy)]
+pub struct Bernoulli {
+    pub p: f64,
+}
+
+impl Bernoulli {
+    pub fn new(p: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "bernoulli: p = {p} is not a probability"
+        );
+        Self { p }
+    }
+}
+
+impl Distribution for Bernoulli {
+    type Output = bool;
+
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> bool {
+        unit(word(rng, index)) < self.p
+    }
+}
+
+/// Waiting times with the given `rate`, e.g. jump arrivals; mean `1 / rate`.
+#[derive(Debug, Clone, Copy)]
+pub struct Exponential<F> {
+    pub rate: F,
+}
+
+impl<F: Real> Exponential<F> {
+    pub fn new(rate: F) -> Self {
+        assert!(
+            rate > F::zero(),
+            "exponential: rate {rate:?} is not positive"
+        );
+        Self { rate }
+    }
+}
+
+impl<F: Real> Distribution for Exponential<F> {
+    type Output = F;
+
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> F {
+        F::from_f64(-(1.0 - unit(word(rng, index))).ln()) / self.rate
+    }
+}
+
+/// Index `i` with probability `weights[i] / Σ weights`, by inverse CDF.
+#[derive(Debug, Clone)]
+pub struct Categorical {
+    cdf: Arc<Vec<f64>>,
+}
+
+impl Categorical {
+    pub fn new(weights: &[f64]) -> Self {
+        assert!(!weights.is_empty(), "categorical: no categories");
+        assert!(
+            weights.iter().all(|&w| w >= 0.0 && w.is_finite()),
+            "categorical: weights must be finite and non-negative"
+        );
+        let mut cdf: Vec<f64> = weights
+            .iter()
+            .scan(0.0, |acc, &w| {
+                *acc += w;
+                Some(*acc)
+            })
+            .collect();
+        let total = *cdf.last().unwrap();
+        assert!(total > 0.0, "categorical: weights sum to zero");
+        cdf.iter_mut().for_each(|c| *c /= total);
+        Self { cdf: Arc::new(cdf) }
+    }
+}
+
+impl Distribution for Categorical {
+    type Output = usize;
+
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> usize {
+        let u = unit(word(rng, index));
+        // Rounding can leave the last entry just below 1.
+        self.cdf
+            .partition_point(|&c| c <= u)
+            .min(self.cdf.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TradingFloat;
+    use std::panic::AssertUnwindSafe;
+
+    #[test]
+    fn test_counter_rngs() {
+        // Known-answer vectors from the Random123 distribution
+        assert_eq!(
+            philox4x32_10([0; 4], [0; 2]),
+            [0x6627_e8d5, 0xe169_c58d, 0xbc57_ac4c, 0x9b00_dbd8]
+        );
+        assert_eq!(
+            philox4x32_10(
+                [0x243f_6a88, 0x85a3_08d3, 0x1319_8a2e, 0x0370_7344],
+                [0xa409_3822, 0x299f_31d0]
+            ),
+            [0xd16c_fe09, 0x94fd_cceb, 0x5001_e420, 0x2412_6ea1]
+        );
+        assert_eq!(
+            threefry2x64_20([0; 2], [0; 2]),
+            [0xc2b6_e3a8_c2c6_9865, 0x6f81_ed42_f350_084d]
+        );
+        assert_eq!(
+            threefry2x64_20(
+                [0x243f_6a88_85a3_08d3, 0x1319_8a2e_0370_7344],
+                [0xa409_3822_299f_31d0, 0x082e_fa98_ec4e_6c89]
+            ),
+            [0x263c_7d30_bb0f_0af1, 0x56be_8361_d331_1526]
+        );
+
+        // Streams of one seed are distinct, and so are seeds.
+        let rng = Philox::new(42, 0);
+        assert_ne!(rng.block(0), Philox::new(42, 1).block(0));
+        assert_ne!(rng.block(0), Philox::new(43, 0).block(0));
+        assert_ne!(rng.block(0), rng.block(1));
+    }
+
+    #[test]
+    fn test_distributions() {
+        let n = 20_000;
+        let rng = Threefry::new(7, 3);
+        let moments = |xs: Vec<f64>| {
+            let mean = xs.iter().sum::<f64>() / xs.len() as f64;
+            let var = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / xs.len() as f64;
+            (mean, var)
+        };
+        let draw = |d: &dyn Fn(u64) -> f64| moments((0..n).map(d).collect());
+
+        let uniform = Uniform::new(TradingFloat::new(-1.0), TradingFloat::new(3.0));
+        let (mean, var) = draw(&|i| uniform.sample(&rng, i).to_f64());
+        assert!((mean - 1.0).abs() < 0.05 && (var - 16.0 / 12.0).abs() < 0.05);
+
+        let normal = Normal::new(TradingFloat::new(0.5), TradingFloat::new(2.0));
+        let (mean, var) = draw(&|i| normal.sample(&rng, i).to_f64());
+        assert!((mean - 0.5).abs() < 0.05 && (var - 4.0).abs() < 0.15);
+
+        let exponential = Exponential::new(TradingFloat::new(4.0));
+        let (mean, var) = draw(&|i| exponential.sample(&rng, i).to_f64());
+        assert!((mean - 0.25).abs() < 0.01 && (var - 0.0625).abs() < 0.01);
+
+        let coin = Bernoulli::new(0.3);
+        let (mean, _) = draw(&|i| coin.sample(&rng, i) as u8 as f64);
+        assert!((mean - 0.3).abs() < 0.02);
+
+        let die = Categorical::new(&[1.0, 0.0, 3.0]);
+        let mut counts = [0; 3];
+        (0..n).for_each(|i| counts[die.sample(&rng, i)] += 1);
+        assert_eq!(counts[1], 0);
+        assert!((counts[2] as f64 / n as f64 - 0.75).abs() < 0.02);
+
+        // A sample depends only on its index.
+        assert_eq!(normal.sample(&rng, 1234), normal.sample(&rng, 1234));
+
+        // Invalid parameters are rejected in every build rather than sampled.
+        fn rejects<T>(f: impl FnOnce() -> T) -> bool {
+            std::panic::catch_unwind(AssertUnwindSafe(f)).is_err()
+        }
+        let t = TradingFloat::new;
+        assert!(rejects(|| Uniform::new(t(1.0), t(1.0))));
+        assert!(rejects(|| Uniform::new(t(2.0), t(1.0))));
+        assert!(rejects(|| Normal::new(t(0.0), t(0.0))));
+        assert!(rejects(|| Normal::new(t(0.0), t(-1.0))));
+        assert!(rejects(|| Exponential::new(t(0.0))));
+        assert!(rejects(|| Bernoulli::new(1.5)));
+        assert!(rejects(|| Categorical::new(&[0.0, 0.0])));
+    }
+}
@@ -0,0 +1,554 @@
+use super::{
+    ApproxEq, ArithmeticError, CheckedArith, Discretization, Field, One, OrderedField, Promote,
+    Ring, Semiring, TradingFloat, Zero,
+};
+use core::cmp::Ordering;
+use core::fmt::{self, Display};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Rational is an exact fraction `num / den` over i128, meant as a golden reference
+/// for float kernels and reduction orders.
+/// Invariants:
+/// - `den > 0` and `gcd(num, den) == 1`, so equal values have equal representations
+/// - Every operation is exact; overflow and division by zero panic in every build,
+///   use the `checked_*` methods or `Checked<Rational>` to handle them as values
+/// - Conversion to `f64`/`TradingFloat` is correctly rounded (half to even)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self::from_int(0);
+    pub const ONE: Self = Self::from_int(1);
+
+    pub const fn from_int(value: i128) -> Self {
+        Self { num: value, den: 1 }
+    }
+
+    /// Panics if `den == 0` or the normalised fraction does not fit.
+    pub fn new(num: i128, den: i128) -> Self {
+        Self::try_new(num, den).unwrap_or_else(|err| panic!("Rational {}: {}/{}", err, num, den))
+    }
+
+    pub fn try_new(num: i128, den: i128) -> Result<Self, ArithmeticError> {
+        if den == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            let num = num.checked_neg().ok_or(ArithmeticError::Overflow)?;
+            let den = den.checked_neg().ok_or(ArithmeticError::Overflow)?;
+            Ok(Self { num, den })
+        } else {
+            Ok(Self { num, den })
+        }
+    }
+
+    pub const fn numer(self) -> i128 {
+        self.num
+    }
+
+    pub const fn denom(self) -> i128 {
+        self.den
+    }
+
+    /// Correctly rounded, ties to even.
+    pub fn to_f64(self) -> f64 {
+        let magnitude = div_to_f64(self.num.unsigned_abs(), self.den.unsigned_abs());
+        if self.num < 0 { -magnitude } else { magnitude }
+    }
+
+    pub fn to_trading_float(self) -> TradingFloat {
+        TradingFloat::new(self.to_f64())
+    }
+}
+
+// Binary gcd; gcd(0, 0) = 1 so that normalising zero divides by one.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    if a == 0 || b == 0 {
+        return (a | b).max(1);
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+// a / b rounded half to even, for b > 0. Produces a 54-bit quotient (53 bits plus the
+// rounding bit) by long division, with the remainder as the sticky bit.
+fn div_to_f64(a: u128, b: u128) -> f64 {
+    if a == 0 {
+        return 0.0;
+    }
+    let (mut q, mut r) = (a / b, a % b);
+    let mut exp: i32 = 0;
+    let mut sticky = false;
+
+    let bits = 128 - q.leading_zeros() as i32;
+    if bits > 54 {
+        let shift = bits - 54;
+        sticky = q & ((1 << shift) - 1) != 0;
+        q >>= shift;
+        exp = shift;
+    } else {
+        while q < 1 << 53 {
+            // r < b <= 2^127, so doubling never overflows.
+            r <<= 1;
+            q <<= 1;
+            if r >= b {
+                r -= b;
+                q |= 1;
+            }
+            exp -= 1;
+        }
+    }
+    sticky |= r != 0;
+
+    let mut mantissa = (q >> 1) as u64;
+    if q & 1 == 1 && (sticky || mantissa & 1 == 1) {
+        mantissa += 1;
+    }
+    // |a / b| lies within [2^-127, 2^128), far from the subnormal and overflow ranges.
+    mantissa as f64 * f64::from_bits(((exp + 1 + 1023) as u64) << 52)
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = &'static str;
+
+    /// Parses `num` or `num/den`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (num, den) = s.split_once('/').unwrap_or((s, "1"));
+        let num = num
+            .trim()
+            .parse()
+            .map_err(|_| "Invalid Rational numerator")?;
+        let den = den
+            .trim()
+            .parse()
+            .map_err(|_| "Invalid Rational denominator")?;
+        Self::try_new(num, den).map_err(|_| "Rational literal out of range")
+    }
+}
+
+impl TryFrom<TradingFloat> for Rational {
+    type Error = &'static str;
+
+    /// Exact: every finite float is a dyadic fraction. Fails if it does not fit i128.
+    fn try_from(value: TradingFloat) -> Result<Self, Self::Error> {
+        let x = value.to_f64();
+        if x == 0.0 {
+            return Ok(Self::ZERO);
+        }
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mut mantissa, mut exp) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        let zeros = mantissa.trailing_zeros() as i32;
+        mantissa >>= zeros;
+        exp += zeros;
+        if x < 0.0 {
+            mantissa = -mantissa;
+        }
+
+        let out_of_range = "Value out of Rational range";
+        if exp >= 0 {
+            let factor = 1i128.checked_shl(exp as u32).filter(|&f| f > 0);
+            factor
+                .and_then(|f| mantissa.checked_mul(f))
+                .map(Self::from_int)
+                .ok_or(out_of_range)
+        } else if exp > -127 {
+            Ok(Self {
+                num: mantissa,
+                den: 1 << -exp,
+            })
+        } else {
+            Err(out_of_range)
+        }
+    }
+}
+
+impl From<Rational> for TradingFloat {
+    fn from(value: Rational) -> Self {
+        value.to_trading_float()
+    }
+}
+
+impl CheckedArith for Rational {
+    fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        let g = gcd(self.den as u128, rhs.den as u128) as i128;
+        let (l, r) = (self.den / g, rhs.den / g);
+        let num = self
+            .num
+            .checked_mul(r)
+            .zip(rhs.num.checked_mul(l))
+            .and_then(|(a, b)| a.checked_add(b));
+        let den = l.checked_mul(rhs.den);
+        match (num, den) {
+            (Some(num), Some(den)) => Self::try_new(num, den),
+            _ => Err(ArithmeticError::Overflow),
+        }
+    }
+
+    fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    // Cross-cancelling first keeps the result normalised and the products small.
+    fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        let g1 = gcd(self.num.unsigned_abs(), rhs.den as u128) as i128;
+        let g2 = gcd(rhs.num.unsigned_abs(), self.den as u128) as i128;
+        let num = (self.num / g1).checked_mul(rhs.num / g2);
+        let den = (self.den / g2).checked_mul(rhs.den / g1);
+        match (num, den) {
+            (Some(num), Some(den)) => Ok(Self { num, den }),
+            _ => Err(ArithmeticError::Overflow),
+        }
+    }
+
+    fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    // Truncated remainder, same sign as `self` like the integer `%`.
+    fn checked_rem(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        let q = self.checked_div(rhs)?;
+        let trunc = Self::from_int(q.num / q.den);
+        self.checked_sub(trunc.checked_mul(rhs)?)
+    }
+
+    fn checked_neg(self) -> Result<Self, ArithmeticError> {
+        self.num
+            .checked_neg()
+            .map(|num| Self { num, den: self.den })
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    fn checked_recip(self) -> Result<Self, ArithmeticError> {
+        Self::try_new(self.den, self.num)
+    }
+}
+
+fn expect_exact(
+    result: Result<Rational, ArithmeticError>,
+    op: &str,
+    lhs: Rational,
+    rhs: Rational,
+) -> Rational {
+    result.unwrap_or_else(|err| panic!("Rational {}: {} {} {}", err, lhs, op, rhs))
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        expect_exact(self.checked_add(rhs), "+", self, rhs)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        expect_exact(self.checked_sub(rhs), "-", self, rhs)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        expect_exact(self.checked_mul(rhs), "*", self, rhs)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        expect_exact(self.checked_div(rhs), "/", self, rhs)
+    }
+}
+
+impl Rem for Rational {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        expect_exact(self.checked_rem(rhs), "%", self, rhs)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.checked_neg().expect("Rational overflow: negating MIN")
+    }
+}
+
+impl AddAssign for Rational {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Rational {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Rational {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Rational {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl RemAssign for Rational {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+// Compares continued fraction expansions, so no cross product can overflow.
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b, mut c, mut d) = (self.num, self.den, other.num, other.den);
+        loop {
+            let (q1, q2) = (a.div_euclid(b), c.div_euclid(d));
+            if q1 != q2 {
+                return q1.cmp(&q2);
+            }
+            let (r1, r2) = (a.rem_euclid(b), c.rem_euclid(d));
+            match (r1 == 0, r2 == 0) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                // r1/b < r2/d  <=>  d/r2 < b/r1
+                (false, false) => (a, b, c, d) = (d, r2, b, r1),
+            }
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Sum for Rational {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl Product for Rational {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl Zero for Rational {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl One for Rational {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl Semiring for Rational {}
+impl Ring for Rational {}
+impl Field for Rational {
+    #[inline]
+    fn recip(self) -> Self {
+        expect_exact(self.checked_recip(), "/", Self::ONE, self)
+    }
+}
+
+impl OrderedField for Rational {
+    #[inline]
+    fn abs(self) -> Self {
+        if self.num < 0 { -self } else { self }
+    }
+    #[inline]
+    fn signum(self) -> Self {
+        Self::from_int(self.num.signum())
+    }
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    #[inline]
+    fn clamp(self, lo: Self, hi: Self) -> Self {
+        Ord::clamp(self, lo, hi)
+    }
+}
+
+// `round` goes half away from zero like `f64::round`.
+impl Discretization for Rational {
+    #[inline]
+    fn floor(self) -> Self {
+        Self::from_int(self.num.div_euclid(self.den))
+    }
+    #[inline]
+    fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+    #[inline]
+    fn round(self) -> Self {
+        let half = Self { num: 1, den: 2 };
+        if self.num < 0 {
+            -(-self).round()
+        } else {
+            (self + half).floor()
+        }
+    }
+}
+
+// Tolerances are applied to the correctly rounded f64 values.
+impl ApproxEq for Rational {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.to_f64().abs_diff_eq(&other.to_f64(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.to_f64().relative_eq(&other.to_f64(), max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.to_f64().ulps_eq(&other.to_f64(), max_ulps)
+    }
+}
+
+impl Promote<Rational> for Rational {
+    type Output = Rational;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Rational) -> Self::Output {
+        rhs
+    }
+}
+
+// Mixing with floats leaves the exact world, rounding once.
+impl Promote<TradingFloat> for Rational {
+    type Output = TradingFloat;
+
+    fn promote_left(self) -> Self::Output {
+        self.to_trading_float()
+    }
+
+    fn promote_right(rhs: TradingFloat) -> Self::Output {
+        rhs
+    }
+}
+
+impl Promote<Rational> for TradingFloat {
+    type Output = TradingFloat;
+
+    fn promote_left(self) -> Self::Output {
+        self
+    }
+
+    fn promote_right(rhs: Rational) -> Self::Output {
+        rhs.to_trading_float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(s: &str) -> Rational {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_rational_exact_reference() {
+        assert_eq!(q("2/4"), q("1/2"));
+        assert_eq!(q("1/-3").to_string(), "-1/3");
+        assert_eq!(q("1/3") + q("1/6"), q("1/2"));
+        assert_eq!(q("1/3") * q("3/7") / q("1/7"), Rational::ONE);
+        assert_eq!(q("7/2") % q("1"), q("1/2"));
+        assert_eq!(q("-7/2") % q("1"), q("-1/2"));
+        assert!(q("1/3") < q("1/2") && q("-1/2") < q("-1/3"));
+        assert!(
+            q("170141183460469231731687303715884105727/2")
+                > q("85070591730234615865843651857942052862")
+        );
+        assert_eq!(q("-5/2").round(), q("-3"));
+        assert_eq!(q("-5/2").floor(), q("-3"));
+
+        // Correct rounding: 0.1 is the nearest double to 1/10, ties go to even.
+        assert_eq!(q("1/10").to_f64(), 0.1);
+        assert_eq!(q("2/3").to_f64(), 2.0 / 3.0);
+        let tie = Rational::new((1 << 53) + 1, 1);
+        assert_eq!(tie.to_f64(), (1u64 << 53) as f64);
+        assert_eq!(
+            Rational::new((1 << 53) + 3, 1).to_f64(),
+            ((1u64 << 53) + 4) as f64
+        );
+
+        // Floats convert exactly, so a float sum can be checked against the exact one.
+        let xs = [0.1, 0.2, 0.3].map(|x| Rational::try_from(TradingFloat::new(x)).unwrap());
+        let exact: Rational = xs.iter().copied().sum();
+        assert_eq!(exact.to_f64(), 0.6);
+        assert_ne!(0.1 + 0.2 + 0.3, 0.6);
+
+        assert_eq!(
+            Rational::ONE.checked_div(Rational::ZERO),
+            Err(ArithmeticError::DivisionByZero)
+        );
+        assert_eq!(
+            Rational::from_int(i128::MAX).checked_add(Rational::ONE),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+}
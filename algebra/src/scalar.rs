@@ -1,4 +1,7 @@
-use super::{Discretization, Field, One, OrderedField, Promote, Real, Ring, Semiring, Zero};
+use super::{
+    ApproxEq, ArithmeticError, CheckedArith, Compensated, Discretization, Field, NextFloat, One,
+    OrderedField, Promote, Real, Ring, Semiring, Zero,
+};
 use core::cmp::Ordering;
 use core::fmt::{self, Display};
 use core::hash::{Hash, Hasher};
@@ -15,8 +18,13 @@ use core::ops::{
 /// - NaN and ±Inf are forbidden
 /// - Equality follows IEEE-754 (`0.0 == -0.0`)
 /// - Hashing is bitwise with canonicalized zero
+/// - Ordering is IEEE-754 `totalOrder` with canonicalized zero and never panics
 /// - No epsilon comparisons are used
 /// - Default is 0.0
+///
+/// Operators check the invariants with `debug_assert!` semantics; enable the `strict`
+/// feature to keep the checks in release builds, or use the `checked_*` / `saturating_*`
+/// methods (or the `Checked` scalar) to handle failures as values.
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub struct TradingFloat(f64);
@@ -52,6 +60,124 @@ impl TradingFloat {
     pub fn to_f32(self) -> f32 {
         self.0 as f32
     }
+
+    // Classifies a raw f64 result: finite passes, ±Inf overflowed, NaN left the domain.
+    #[inline]
+    fn check(result: f64) -> Result<Self, ArithmeticError> {
+        if result.is_finite() {
+            Ok(TradingFloat(result))
+        } else if result.is_nan() {
+            Err(ArithmeticError::Domain)
+        } else {
+            Err(ArithmeticError::Overflow)
+        }
+    }
+
+    // Overflow clamps to ±MAX; only domain errors and division by zero remain errors.
+    #[inline]
+    fn saturate(result: f64) -> Result<Self, ArithmeticError> {
+        if result.is_nan() {
+            Err(ArithmeticError::Domain)
+        } else {
+            Ok(TradingFloat(result.clamp(f64::MIN, f64::MAX)))
+        }
+    }
+
+    pub fn checked_exp(self) -> Result<Self, ArithmeticError> {
+        Self::check(self.0.exp())
+    }
+
+    pub fn checked_ln(self) -> Result<Self, ArithmeticError> {
+        if self.0 <= 0.0 {
+            return Err(ArithmeticError::Domain);
+        }
+        Self::check(self.0.ln())
+    }
+
+    pub fn checked_sqrt(self) -> Result<Self, ArithmeticError> {
+        if self.0 < 0.0 {
+            return Err(ArithmeticError::Domain);
+        }
+        Self::check(self.0.sqrt())
+    }
+
+    pub fn checked_pow(self, exp: Self) -> Result<Self, ArithmeticError> {
+        if self.0 == 0.0 && exp.0 < 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Self::check(self.0.powf(exp.0))
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        Self::saturate(self.0 + rhs.0)
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        Self::saturate(self.0 - rhs.0)
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        Self::saturate(self.0 * rhs.0)
+    }
+
+    pub fn saturating_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Self::saturate(self.0 / rhs.0)
+    }
+
+    pub fn saturating_recip(self) -> Result<Self, ArithmeticError> {
+        Self::ONE.saturating_div(self)
+    }
+
+    pub fn saturating_exp(self) -> Result<Self, ArithmeticError> {
+        Self::saturate(self.0.exp())
+    }
+
+    pub fn saturating_pow(self, exp: Self) -> Result<Self, ArithmeticError> {
+        if self.0 == 0.0 && exp.0 < 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Self::saturate(self.0.powf(exp.0))
+    }
+}
+
+impl CheckedArith for TradingFloat {
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        Self::check(self.0 + rhs.0)
+    }
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        Self::check(self.0 - rhs.0)
+    }
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        Self::check(self.0 * rhs.0)
+    }
+    #[inline]
+    fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Self::check(self.0 / rhs.0)
+    }
+    #[inline]
+    fn checked_rem(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Self::check(self.0 % rhs.0)
+    }
+    #[inline]
+    fn checked_neg(self) -> Result<Self, ArithmeticError> {
+        Ok(-self)
+    }
+    #[inline]
+    fn checked_recip(self) -> Result<Self, ArithmeticError> {
+        Self::ONE.checked_div(self)
+    }
 }
 
 impl Default for TradingFloat {
@@ -67,12 +193,11 @@ impl Display for TradingFloat {
 }
 
 impl PartialEq for TradingFloat {
-    // TODO: Approximate Equality
-    // While `Eq` must be exact for HashMaps, trading logic often requires `approx_eq`
-    // to handle floating point drift (e.g. 1.00000000001 vs 1.0).
-
+    // Exact, so HashMaps stay sound. Use `ApproxEq` for floating point drift.
+    // Canonical bits agree with IEEE `==` on every valid value (zeros are canonicalized),
+    // and keep `Eq`, `Hash` and `Ord` consistent should a NaN slip through unchecked math.
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        self.to_bits() == other.to_bits()
     }
 }
 
@@ -91,12 +216,25 @@ impl PartialOrd for TradingFloat {
 }
 
 impl Ord for TradingFloat {
-    // TODO: Safety in Release Mode.
-    // `debug_assert!` is stripped in release. If a NaN sneaks in (via unchecked math),
-    // `unwrap/expect` here will panic the application in production.
-    // Fallback to a default ordering (e.g., treat NaN as 0.0) to prevent crashes.
+    // IEEE-754 `totalOrder` over the canonical bits, so `-0.0 == 0.0` as required by `Eq`.
+    // Should a NaN sneak in (via unchecked release math) it sorts instead of panicking:
+    // -NaN < -Inf < ... < -0.0 == +0.0 < ... < +Inf < +NaN, payloads ordered by bits.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.partial_cmp(&other.0).expect("NaN in TradingFloat")
+        f64::from_bits(self.to_bits()).total_cmp(&f64::from_bits(other.to_bits()))
+    }
+}
+
+impl ApproxEq for TradingFloat {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+        self.0.ulps_eq(&other.0, max_ulps)
     }
 }
 
@@ -124,12 +262,7 @@ impl Add for TradingFloat {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
         let result = self.0 + rhs.0;
-        // TODO: Release Mode Invariant Checking.
-        // `debug_assert!` does nothing in release.
-        // If `result` becomes Infinity here, the struct invariant is broken,
-        // but the code proceeds. Consider if `check_finite` is needed in release too.
-        // Consider strict feature flag
-        debug_assert!(
+        invariant!(
             result.is_finite(),
             "TradingFloat addition overflow: {} + {}",
             self.0,
@@ -143,7 +276,7 @@ impl Sub for TradingFloat {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
         let result = self.0 - rhs.0;
-        debug_assert!(
+        invariant!(
             result.is_finite(),
             "TradingFloat subtraction overflow: {} - {}",
             self.0,
@@ -157,7 +290,7 @@ impl Mul for TradingFloat {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
         let result = self.0 * rhs.0;
-        debug_assert!(
+        invariant!(
             result.is_finite(),
             "TradingFloat multiplication overflow: {} * {}",
             self.0,
@@ -170,12 +303,12 @@ impl Mul for TradingFloat {
 impl Div for TradingFloat {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
-        debug_assert!(
+        invariant!(
             rhs.0 != 0.0,
             "TradingFloat invariant violated: division by zero"
         );
         let result = self.0 / rhs.0;
-        debug_assert!(
+        invariant!(
             result.is_finite(),
             "TradingFloat division overflow: {} / {}",
             self.0,
@@ -195,12 +328,12 @@ impl Neg for TradingFloat {
 impl Rem for TradingFloat {
     type Output = Self;
     fn rem(self, rhs: Self) -> Self::Output {
-        debug_assert!(
+        invariant!(
             rhs.0 != 0.0,
             "TradingFloat invariant violated: modulo by zero"
         );
         let result = self.0 % rhs.0;
-        debug_assert!(
+        invariant!(
             result.is_finite(),
             "TradingFloat remainder invalid: {} % {}",
             self.0,
@@ -246,10 +379,9 @@ impl RemAssign for TradingFloat {
 }
 
 impl Sum for TradingFloat {
-    // TODO: Kahan Summation / Pairwise Summation.
-    // Naive iteration accumulates floating point error significantly in large trading lists.
+    // Neumaier-compensated: long PnL series must not drift with their length.
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(TradingFloat::ZERO, |acc, x| acc + x)
+        iter.fold(Compensated::new(), Compensated::push).value()
     }
 }
 
@@ -274,18 +406,21 @@ impl One for TradingFloat {
 
 // Allows MatMul and Scan
 impl Semiring for TradingFloat {}
+// Scalar lanes: comparisons follow the total order, arithmetic checks every result.
 // Allows Subtraction and Physics
 impl Ring for TradingFloat {}
 // Allows division and linear algebra
 impl Field for TradingFloat {
     #[inline]
     fn recip(self) -> Self {
-        debug_assert!(
-            self.0 != 0.0,
+        let result = 1.0 / self.0;
+        // Also catches subnormals, whose reciprocal overflows.
+        invariant!(
+            result.is_finite(),
             "TradingFloat division overflow: 1 / {}",
             self.0
         );
-        TradingFloat(1.0 / self.0)
+        TradingFloat(result)
     }
 }
 
@@ -314,7 +449,7 @@ impl OrderedField for TradingFloat {
     }
     #[inline]
     fn clamp(self, lo: Self, hi: Self) -> Self {
-        debug_assert!(
+        invariant!(
             lo <= hi,
             "TradingFloat clamp invariant violated: {} > {}",
             lo.0,
@@ -336,24 +471,37 @@ impl Real for TradingFloat {
     }
     #[inline]
     fn exp(self) -> Self {
-        TradingFloat(self.0.exp())
+        let result = self.0.exp();
+        invariant!(result.is_finite(), "TradingFloat exp overflow: {}", self.0);
+        TradingFloat(result)
     }
 
     #[inline]
     fn ln(self) -> Self {
-        debug_assert!(self.0 > 0.0);
+        invariant!(self.0 > 0.0, "TradingFloat ln domain violated: {}", self.0);
         TradingFloat(self.0.ln())
     }
 
     #[inline]
     fn sqrt(self) -> Self {
-        debug_assert!(self.0 >= 0.0);
+        invariant!(
+            self.0 >= 0.0,
+            "TradingFloat sqrt domain violated: {}",
+            self.0
+        );
         TradingFloat(self.0.sqrt())
     }
 
     #[inline]
     fn pow(self, exp: Self) -> Self {
-        TradingFloat(self.0.powf(exp.0))
+        let result = self.0.powf(exp.0);
+        invariant!(
+            result.is_finite(),
+            "TradingFloat pow invalid: {} ^ {}",
+            self.0,
+            exp.0
+        );
+        TradingFloat(result)
     }
     #[inline]
     fn sin(self) -> Self {
@@ -363,6 +511,113 @@ impl Real for TradingFloat {
     fn cos(self) -> Self {
         TradingFloat(self.0.cos())
     }
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        TradingFloat(self.0.atan2(other.0))
+    }
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        TradingFloat::new(x)
+    }
+    #[inline]
+    fn sinh(self) -> Self {
+        let result = self.0.sinh();
+        invariant!(result.is_finite(), "TradingFloat sinh overflow: {}", self.0);
+        TradingFloat(result)
+    }
+    #[inline]
+    fn cosh(self) -> Self {
+        let result = self.0.cosh();
+        invariant!(result.is_finite(), "TradingFloat cosh overflow: {}", self.0);
+        TradingFloat(result)
+    }
+    #[inline]
+    fn tanh(self) -> Self {
+        TradingFloat(self.0.tanh())
+    }
+    #[inline]
+    fn atan(self) -> Self {
+        TradingFloat(self.0.atan())
+    }
+    #[inline]
+    fn log1p(self) -> Self {
+        invariant!(
+            self.0 > -1.0,
+            "TradingFloat log1p domain violated: {}",
+            self.0
+        );
+        TradingFloat(self.0.ln_1p())
+    }
+    #[inline]
+    fn expm1(self) -> Self {
+        let result = self.0.exp_m1();
+        invariant!(
+            result.is_finite(),
+            "TradingFloat expm1 overflow: {}",
+            self.0
+        );
+        TradingFloat(result)
+    }
+    #[inline]
+    fn log2(self) -> Self {
+        invariant!(
+            self.0 > 0.0,
+            "TradingFloat log2 domain violated: {}",
+            self.0
+        );
+        TradingFloat(self.0.log2())
+    }
+    #[inline]
+    fn log10(self) -> Self {
+        invariant!(
+            self.0 > 0.0,
+            "TradingFloat log10 domain violated: {}",
+            self.0
+        );
+        TradingFloat(self.0.log10())
+    }
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        let result = self.0.powi(n);
+        invariant!(
+            result.is_finite(),
+            "TradingFloat powi invalid: {} ^ {}",
+            self.0,
+            n
+        );
+        TradingFloat(result)
+    }
+    // erf and the normal functions use the generic implementations, which are
+    // accurate to a few ulps in f64; only the gamma poles need an explicit check.
+    fn lgamma(self) -> Self {
+        invariant!(
+            self.0 > 0.0 || self.0.fract() != 0.0,
+            "TradingFloat lgamma at a pole: {}",
+            self.0
+        );
+        crate::special::lgamma(self)
+    }
+    fn gamma(self) -> Self {
+        invariant!(
+            self.0 > 0.0 || self.0.fract() != 0.0,
+            "TradingFloat gamma at a pole: {}",
+            self.0
+        );
+        crate::special::gamma(self)
+    }
+}
+
+// Steps past ±MAX to ±inf and keeps infinities, like f64: outward rounding must stay an
+// upper (lower) bound, and clamping an overflowed endpoint back to MAX would not be one.
+impl NextFloat for TradingFloat {
+    #[inline]
+    fn next_up(self) -> Self {
+        TradingFloat(self.0.next_up())
+    }
+    #[inline]
+    fn next_down(self) -> Self {
+        TradingFloat(self.0.next_down())
+    }
 }
 
 impl Discretization for TradingFloat {
@@ -423,3 +678,248 @@ impl Promote<TradingFloat> for bool {
         rhs
     }
 }
+
+#[cfg(all(test, feature = "fuzz"))]
+mod fuzz {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::panic::{self, AssertUnwindSafe};
+
+    const ITERATIONS: usize = 200_000;
+
+    // xorshift64*: deterministic, so a failure reproduces from the iteration number alone.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+        }
+
+        // Biased towards the values that break float code.
+        fn adversarial_bits(&mut self) -> u64 {
+            const SPECIAL: [u64; 14] = [
+                0x0000_0000_0000_0000, // +0
+                0x8000_0000_0000_0000, // -0
+                0x0000_0000_0000_0001, // smallest subnormal
+                0x000F_FFFF_FFFF_FFFF, // largest subnormal
+                0x0010_0000_0000_0000, // MIN_POSITIVE
+                0x3FF0_0000_0000_0000, // 1
+                0xBFF0_0000_0000_0000, // -1
+                0x7FEF_FFFF_FFFF_FFFF, // MAX
+                0xFFEF_FFFF_FFFF_FFFF, // MIN
+                0x7FF0_0000_0000_0000, // +Inf
+                0xFFF0_0000_0000_0000, // -Inf
+                0x7FF8_0000_0000_0000, // quiet NaN
+                0xFFF8_0000_0000_0001, // negative NaN with payload
+                0x7FF0_0000_0000_0001, // signalling NaN
+            ];
+            let r = self.next();
+            match r % 4 {
+                0 => SPECIAL[(r >> 8) as usize % SPECIAL.len()],
+                // Extreme exponents with random mantissa and sign
+                1 => {
+                    let exp = if r & 0x100 == 0 {
+                        0x7FE
+                    } else {
+                        (r >> 9) & 0x3
+                    };
+                    (r & 0x8000_0000_0000_0000)
+                        | (exp << 52)
+                        | (self.next() & 0x000F_FFFF_FFFF_FFFF)
+                }
+                _ => self.next(),
+            }
+        }
+
+        fn finite(&mut self) -> TradingFloat {
+            loop {
+                if let Ok(x) = TradingFloat::try_from(f64::from_bits(self.adversarial_bits())) {
+                    return x;
+                }
+            }
+        }
+    }
+
+    fn hash_of(x: TradingFloat) -> u64 {
+        let mut h = DefaultHasher::new();
+        x.hash(&mut h);
+        h.finish()
+    }
+
+    fn expected(result: f64) -> Result<f64, ArithmeticError> {
+        if result.is_nan() {
+            Err(ArithmeticError::Domain)
+        } else if result.is_infinite() {
+            Err(ArithmeticError::Overflow)
+        } else {
+            Ok(result)
+        }
+    }
+
+    // The raw operator must agree with its checked twin: same value when the checked form
+    // succeeds, and an invariant panic exactly when it fails (if invariants are compiled in).
+    // Expected panics still print: the panic hook is process-wide, and silencing it would
+    // also swallow the output of tests running alongside.
+    fn agree(
+        checked: Result<TradingFloat, ArithmeticError>,
+        raw: impl FnOnce() -> TradingFloat,
+        what: &str,
+    ) {
+        let raw = panic::catch_unwind(AssertUnwindSafe(raw));
+        match (checked, raw) {
+            (Ok(c), Ok(r)) => {
+                assert!(c.to_f64().is_finite(), "{what}: non-finite Ok");
+                assert_eq!(c.to_bits(), r.to_bits(), "{what}: raw/checked mismatch");
+            }
+            (Err(_), Err(_)) => {}
+            (Err(_), Ok(_)) if !cfg!(any(debug_assertions, feature = "strict")) => {}
+            (c, r) => panic!("{what}: checked {c:?} but raw panicked={}", r.is_err()),
+        }
+    }
+
+    #[test]
+    fn fuzz_total_order() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        let mut pool = Vec::with_capacity(4096);
+
+        for _ in 0..ITERATIONS {
+            let a = TradingFloat(f64::from_bits(rng.adversarial_bits()));
+            let b = TradingFloat(f64::from_bits(rng.adversarial_bits()));
+            let c = TradingFloat(f64::from_bits(rng.adversarial_bits()));
+
+            let ab = a.cmp(&b);
+            assert_eq!(ab, b.cmp(&a).reverse(), "antisymmetry {a:?} {b:?}");
+            assert_eq!(ab == Ordering::Equal, a == b, "Ord/Eq {a:?} {b:?}");
+            assert_eq!(a.partial_cmp(&b), Some(ab));
+            assert_eq!(a, a, "reflexivity {a:?}");
+            if a == b {
+                assert_eq!(hash_of(a), hash_of(b), "Hash/Eq {a:?} {b:?}");
+            }
+            if a <= b && b <= c {
+                assert!(a <= c, "transitivity {a:?} {b:?} {c:?}");
+            }
+            // On valid values the order is the numeric one.
+            if a.0.is_finite() && b.0.is_finite() {
+                assert_eq!(Some(ab), a.0.partial_cmp(&b.0), "numeric {a:?} {b:?}");
+            }
+
+            if pool.len() < pool.capacity() {
+                pool.push(a);
+            }
+        }
+
+        pool.sort();
+        assert!(pool.is_sorted());
+    }
+
+    #[test]
+    fn fuzz_operators() {
+        let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+
+        for _ in 0..ITERATIONS {
+            let a = rng.finite();
+            let b = rng.finite();
+            let (x, y) = (a.to_f64(), b.to_f64());
+
+            assert_eq!(a.checked_add(b).map(f64::from), expected(x + y));
+            assert_eq!(a.checked_sub(b).map(f64::from), expected(x - y));
+            assert_eq!(a.checked_mul(b).map(f64::from), expected(x * y));
+            if y == 0.0 {
+                assert_eq!(a.checked_div(b), Err(ArithmeticError::DivisionByZero));
+                assert_eq!(a.checked_rem(b), Err(ArithmeticError::DivisionByZero));
+                assert_eq!(a.saturating_div(b), Err(ArithmeticError::DivisionByZero));
+            } else {
+                assert_eq!(a.checked_div(b).map(f64::from), expected(x / y));
+                assert_eq!(a.checked_rem(b).map(f64::from), expected(x % y));
+            }
+
+            agree(a.checked_add(b), || a + b, "add");
+            agree(a.checked_sub(b), || a - b, "sub");
+            agree(a.checked_mul(b), || a * b, "mul");
+            agree(a.checked_div(b), || a / b, "div");
+            agree(a.checked_rem(b), || a % b, "rem");
+            agree(a.checked_recip(), || a.recip(), "recip");
+            agree(a.checked_exp(), || a.exp(), "exp");
+            agree(a.checked_ln(), || a.ln(), "ln");
+            agree(a.checked_sqrt(), || a.sqrt(), "sqrt");
+            agree(a.checked_pow(b), || a.pow(b), "pow");
+            assert_eq!((-a).to_f64(), -x);
+            assert_eq!(a.abs().to_f64(), x.abs());
+
+            for sat in [
+                a.saturating_add(b),
+                a.saturating_sub(b),
+                a.saturating_mul(b),
+                a.saturating_exp(),
+            ] {
+                assert!(
+                    sat.expect("saturating op failed on finite input")
+                        .0
+                        .is_finite()
+                );
+            }
+            for sat in [
+                a.saturating_div(b),
+                a.saturating_pow(b),
+                a.saturating_recip(),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                assert!(sat.0.is_finite());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ArithmeticError::{DivisionByZero, Domain, Overflow};
+
+    #[test]
+    fn test_checked_boundaries() {
+        let t = TradingFloat::new;
+        let max = t(f64::MAX);
+        assert_eq!(max.checked_add(max), Err(Overflow));
+        assert_eq!((-max).checked_sub(max), Err(Overflow));
+        assert_eq!(max.checked_mul(t(2.0)), Err(Overflow));
+        assert_eq!(max.checked_div(t(0.5)), Err(Overflow));
+        assert_eq!(t(1.0).checked_div(t(0.0)), Err(DivisionByZero));
+        assert_eq!(t(1.0).checked_rem(t(0.0)), Err(DivisionByZero));
+        assert_eq!(t(-0.0).checked_recip(), Err(DivisionByZero));
+        assert_eq!(max.checked_add(-max), Ok(t(0.0)));
+        assert_eq!(max.checked_neg(), Ok(-max));
+
+        assert_eq!(t(710.0).checked_exp(), Err(Overflow));
+        assert_eq!(t(-800.0).checked_exp(), Ok(t(0.0)));
+        assert_eq!(t(0.0).checked_ln(), Err(Domain));
+        assert_eq!(t(-1.0).checked_ln(), Err(Domain));
+        assert_eq!(t(-1e-300).checked_sqrt(), Err(Domain));
+        assert_eq!(t(-0.0).checked_sqrt(), Ok(t(0.0)));
+        assert_eq!(t(0.0).checked_pow(t(-1.0)), Err(DivisionByZero));
+        assert_eq!(t(-8.0).checked_pow(t(0.5)), Err(Domain));
+        assert_eq!(t(10.0).checked_pow(t(400.0)), Err(Overflow));
+    }
+
+    #[test]
+    fn test_saturating_boundaries() {
+        let t = TradingFloat::new;
+        let max = t(f64::MAX);
+        assert_eq!(max.saturating_add(max), Ok(max));
+        assert_eq!((-max).saturating_sub(max), Ok(-max));
+        assert_eq!(max.saturating_mul(t(-2.0)), Ok(-max));
+        assert_eq!(max.saturating_div(t(1e-300)), Ok(max));
+        assert_eq!(t(710.0).saturating_exp(), Ok(max));
+        assert_eq!(t(-10.0).saturating_pow(t(401.0)), Ok(-max));
+
+        // Only overflow saturates; the other failures stay errors.
+        assert_eq!(t(1.0).saturating_div(t(0.0)), Err(DivisionByZero));
+        assert_eq!(t(0.0).saturating_recip(), Err(DivisionByZero));
+        assert_eq!(t(0.0).saturating_pow(t(-2.0)), Err(DivisionByZero));
+        assert_eq!(t(-8.0).saturating_pow(t(1.0 / 3.0)), Err(Domain));
+    }
+}
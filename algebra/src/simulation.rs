@@ -0,0 +1,479 @@
+use super::{
+    CounterRng, DifferentiableKernel, DifferentiableStreamKernel, Distribution, Normal,
+    OrderedField, Real, StreamKernel, UnaryKernel,
+};
+use core::marker::PhantomData;
+
+// Monte Carlo path kernels: each is a `StreamKernel` stepped along a time axis, one path
+// per row, e.g. `shocks.stream::<Time, _>(gbm)` for `shocks` of shape [Path, Time].
+// Every model reads independent standard normals, so antithetic variates and any other
+// shock source (pseudo-random, QMC) plug in unchanged. Each also carries d S / d S_0 along
+// the path, so a payoff taped over `stream` pulls back to pathwise deltas per path.
+
+/// `N` independent standard normals per element: the shocks of one step of an N-factor
+/// model. The antithetic twin reads the same indices and negates them, so the mirrored
+/// paths need neither storage nor a second stream.
+#[derive(Debug, Clone, Copy)]
+pub struct StandardNormals<F, const N: usize> {
+    antithetic: bool,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Real, const N: usize> StandardNormals<F, N> {
+    pub fn new() -> Self {
+        Self {
+            antithetic: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn antithetic(self) -> Self {
+        Self {
+            antithetic: !self.antithetic,
+            ..self
+        }
+    }
+}
+
+impl<F: Real, const N: usize> Default for StandardNormals<F, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Real, const N: usize> Distribution for StandardNormals<F, N> {
+    type Output = [F; N];
+
+    fn sample<G: CounterRng>(&self, rng: &G, index: u64) -> [F; N] {
+        let normal = Normal::<F>::standard();
+        core::array::from_fn(|k| {
+            let z = normal.sample(rng, index * N as u64 + k as u64);
+            if self.antithetic { -z } else { z }
+        })
+    }
+}
+
+fn half<F: Real>() -> F {
+    F::from_f64(0.5)
+}
+
+/// Geometric Brownian motion, stepped exactly in log space:
+/// S ← S exp((r - σ²/2) dt + σ √dt z).
+#[derive(Debug, Clone, Copy)]
+pub struct GbmKernel<F> {
+    pub spot: F,
+    pub rate: F,
+    pub vol: F,
+    pub dt: F,
+}
+
+impl<F: Real> GbmKernel<F> {
+    // S(t + dt) / S(t)
+    fn growth(&self, [z]: [F; 1]) -> F {
+        let drift = (self.rate - half::<F>() * self.vol * self.vol) * self.dt;
+        (drift + self.vol * self.dt.sqrt() * z).exp()
+    }
+}
+
+impl<F: Real> StreamKernel<[F; 1]> for GbmKernel<F> {
+    type State = F;
+    type Output = F;
+
+    fn init(&self) -> F {
+        self.spot
+    }
+
+    #[inline]
+    fn step(&self, spot: &mut F, z: [F; 1]) -> F {
+        *spot = *spot * self.growth(z);
+        *spot
+    }
+}
+
+impl<F: Real> DifferentiableStreamKernel<[F; 1]> for GbmKernel<F> {
+    fn init_tangent(&self) -> F {
+        F::one()
+    }
+
+    #[inline]
+    fn step_tangent(&self, spot: &mut F, tangent: &mut F, z: [F; 1]) -> (F, F) {
+        let growth = self.growth(z);
+        *spot = *spot * growth;
+        *tangent = *tangent * growth;
+        (*spot, *tangent)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HestonScheme {
+    // Euler on the variance with max(v, 0) in drift and diffusion (Lord et al., 2010).
+    FullTruncation,
+    // Andersen's quadratic-exponential scheme (2008): moment-matched variance steps,
+    // accurate at coarse time steps when the Feller condition fails. At ξ = 0 the variance
+    // is deterministic and steps along its mean.
+    QuadraticExponential,
+}
+
+/// Heston stochastic volatility: dS = r S dt + √v S dW₁, dv = κ(θ - v) dt + ξ √v dW₂,
+/// d⟨W₁, W₂⟩ = ρ dt. Input per step: `[z_spot, z_var]`, independent.
+#[derive(Debug, Clone, Copy)]
+pub struct HestonKernel<F> {
+    pub spot: F,
+    pub var0: F,
+    pub rate: F,
+    pub kappa: F,
+    pub theta: F,
+    pub xi: F,
+    pub rho: F,
+    pub dt: F,
+    pub scheme: HestonScheme,
+}
+
+impl<F: Real> HestonKernel<F> {
+    // Both schemes step the variance and return the spot's growth S(t + dt) / S(t).
+    fn full_truncation(&self, var: &mut F, [zs, zv]: [F; 2]) -> F {
+        let v = OrderedField::max(*var, F::zero());
+        let sqrt_vdt = (v * self.dt).sqrt();
+        let zv = self.rho * zs + (F::one() - self.rho * self.rho).sqrt() * zv;
+        *var = *var + self.kappa * (self.theta - v) * self.dt + self.xi * sqrt_vdt * zv;
+        ((self.rate - half::<F>() * v) * self.dt + sqrt_vdt * zs).exp()
+    }
+
+    fn quadratic_exponential(&self, var: &mut F, [zs, zv]: [F; 2]) -> F {
+        let (one, two) = (F::one(), F::from_f64(2.0));
+        let (kappa, theta, xi, rho, dt) = (self.kappa, self.theta, self.xi, self.rho, self.dt);
+        let v = *var;
+
+        // Conditional mean and variance of v(t + dt)
+        let decay = (-kappa * dt).exp();
+        let m = theta + (v - theta) * decay;
+        if xi == F::zero() {
+            // k0..k2 divide by ξ; without vol of vol only the integrated variance remains.
+            let integral = half::<F>() * dt * (v + m);
+            *var = m;
+            return (self.rate * dt - half::<F>() * integral + integral.sqrt() * zs).exp();
+        }
+        let s2 = v * xi * xi * decay * (one - decay) / kappa
+            + theta * xi * xi * (one - decay) * (one - decay) / (two * kappa);
+        let psi = s2 / (m * m);
+        let next = if psi <= F::from_f64(1.5) {
+            // Moment-matched non-central chi-square: a (b + Z)²
+            let b2 = two / psi - one + (two / psi).sqrt() * (two / psi - one).sqrt();
+            let b = b2.sqrt();
+            m / (one + b2) * (b + zv) * (b + zv)
+        } else {
+            // Mass p at zero, exponential tail beyond
+            let p = (psi - one) / (psi + one);
+            let u = zv.norm_cdf();
+            if u <= p {
+                F::zero()
+            } else {
+                ((one - p) / (one - u)).ln() * m / (one - p)
+            }
+        };
+
+        // Log-spot step with the trapezoidal variance integral (γ₁ = γ₂ = 1/2)
+        let h = half::<F>() * dt;
+        let k0 = -rho * kappa * theta * dt / xi;
+        let k1 = h * (kappa * rho / xi - half()) - rho / xi;
+        let k2 = h * (kappa * rho / xi - half()) + rho / xi;
+        let k3 = h * (one - rho * rho);
+        let log_step = self.rate * dt + k0 + k1 * v + k2 * next + ((v + next) * k3).sqrt() * zs;
+        *var = next;
+        log_step.exp()
+    }
+
+    fn growth(&self, var: &mut F, z: [F; 2]) -> F {
+        match self.scheme {
+            HestonScheme::FullTruncation => self.full_truncation(var, z),
+            HestonScheme::QuadraticExponential => self.quadratic_exponential(var, z),
+        }
+    }
+}
+
+impl<F: Real> StreamKernel<[F; 2]> for HestonKernel<F> {
+    // (spot, variance)
+    type State = (F, F);
+    type Output = F;
+
+    fn init(&self) -> (F, F) {
+        (self.spot, self.var0)
+    }
+
+    #[inline]
+    fn step(&self, (spot, var): &mut (F, F), z: [F; 2]) -> F {
+        *spot = *spot * self.growth(var, z);
+        *spot
+    }
+}
+
+impl<F: Real> DifferentiableStreamKernel<[F; 2]> for HestonKernel<F> {
+    // The variance never reads the spot, so its tangent stays zero.
+    fn init_tangent(&self) -> (F, F) {
+        (F::one(), F::zero())
+    }
+
+    #[inline]
+    fn step_tangent(&self, (spot, var): &mut (F, F), tangent: &mut (F, F), z: [F; 2]) -> (F, F) {
+        let growth = self.growth(var, z);
+        *spot = *spot * growth;
+        tangent.0 = tangent.0 * growth;
+        (*spot, tangent.0)
+    }
+}
+
+/// Merton jump diffusion: GBM plus Poisson(λ dt) jumps per step with log-normal sizes
+/// ln J ~ N(μ_J, σ_J²), drift-compensated so the discounted spot stays a martingale.
+/// Input per step: `[z_diffusion, z_count, z_size]`; the count inverts the Poisson CDF at
+/// Φ(z_count), and n jumps sum to n μ_J + √n σ_J z_size.
+#[derive(Debug, Clone, Copy)]
+pub struct MertonKernel<F> {
+    pub spot: F,
+    pub rate: F,
+    pub vol: F,
+    pub intensity: F,
+    pub jump_mean: F,
+    pub jump_vol: F,
+    pub dt: F,
+}
+
+impl<F: Real> MertonKernel<F> {
+    // E[J - 1]
+    fn compensator(&self) -> F {
+        (self.jump_mean + half::<F>() * self.jump_vol * self.jump_vol).exp() - F::one()
+    }
+
+    fn jump_count(&self, u: F) -> u32 {
+        let mean = self.intensity * self.dt;
+        let (mut n, mut p) = (0, (-mean).exp());
+        let mut cdf = p;
+        // The tail beyond 64 jumps in one step is below any float resolution.
+        while u > cdf && n < 64 {
+            n += 1;
+            p = p * mean / F::from_f64(n as f64);
+            cdf = cdf + p;
+        }
+        n
+    }
+
+    // S(t + dt) / S(t)
+    fn growth(&self, [z, z_count, z_size]: [F; 3]) -> F {
+        let drift =
+            (self.rate - self.intensity * self.compensator() - half::<F>() * self.vol * self.vol)
+                * self.dt;
+        let n = F::from_f64(self.jump_count(z_count.norm_cdf()) as f64);
+        let jumps = n * self.jump_mean + n.sqrt() * self.jump_vol * z_size;
+        (drift + self.vol * self.dt.sqrt() * z + jumps).exp()
+    }
+}
+
+impl<F: Real> StreamKernel<[F; 3]> for MertonKernel<F> {
+    type State = F;
+    type Output = F;
+
+    fn init(&self) -> F {
+        self.spot
+    }
+
+    #[inline]
+    fn step(&self, spot: &mut F, z: [F; 3]) -> F {
+        *spot = *spot * self.growth(z);
+        *spot
+    }
+}
+
+impl<F: Real> DifferentiableStreamKernel<[F; 3]> for MertonKernel<F> {
+    fn init_tangent(&self) -> F {
+        F::one()
+    }
+
+    #[inline]
+    fn step_tangent(&self, spot: &mut F, tangent: &mut F, z: [F; 3]) -> (F, F) {
+        let growth = self.growth(z);
+        *spot = *spot * growth;
+        *tangent = *tangent * growth;
+        (*spot, *tangent)
+    }
+}
+
+/// max(S - K, 0); the pathwise derivative is 1{S > K}.
+#[derive(Debug, Clone, Copy)]
+pub struct CallPayoff<F> {
+    pub strike: F,
+}
+
+impl<F: Real> UnaryKernel<F> for CallPayoff<F> {
+    type Output = F;
+
+    #[inline(always)]
+    fn apply(&self, spot: F) -> F {
+        OrderedField::max(spot - self.strike, F::zero())
+    }
+}
+
+impl<F: Real> DifferentiableKernel<F> for CallPayoff<F> {
+    #[inline(always)]
+    fn derivative(&self, spot: F) -> F {
+        if spot > self.strike {
+            F::one()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+/// max(K - S, 0); the pathwise derivative is -1{S < K}.
+#[derive(Debug, Clone, Copy)]
+pub struct PutPayoff<F> {
+    pub strike: F,
+}
+
+impl<F: Real> UnaryKernel<F> for PutPayoff<F> {
+    type Output = F;
+
+    #[inline(always)]
+    fn apply(&self, spot: F) -> F {
+        OrderedField::max(self.strike - spot, F::zero())
+    }
+}
+
+impl<F: Real> DifferentiableKernel<F> for PutPayoff<F> {
+    #[inline(always)]
+    fn derivative(&self, spot: F) -> F {
+        if spot < self.strike {
+            -F::one()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ApproxEq, Philox, TradingFloat, assert_approx_eq};
+
+    fn run<K: StreamKernel<I>, I: Copy>(kernel: K, shocks: &[I]) -> Vec<K::Output> {
+        let mut state = kernel.init();
+        shocks.iter().map(|&z| kernel.step(&mut state, z)).collect()
+    }
+
+    // Every model is linear in its spot, so d S_t / d S_0 = S_t / S_0 along any path.
+    fn check_tangents<K, I, F>(kernel: K, spot: F, shocks: &[I])
+    where
+        K: DifferentiableStreamKernel<I, Output = F>,
+        I: Copy,
+        F: Real + ApproxEq,
+    {
+        let (mut state, mut tangent) = (kernel.init(), kernel.init_tangent());
+        for (&z, s) in shocks.iter().zip(run(kernel, shocks)) {
+            let (out, d_out) = kernel.step_tangent(&mut state, &mut tangent, z);
+            assert_eq!(out, s);
+            assert_approx_eq!(d_out * spot, s);
+        }
+    }
+
+    #[test]
+    fn test_path_kernels() {
+        let t = TradingFloat::new;
+        let (spot, rate, vol, dt) = (t(100.0), t(0.03), t(0.2), t(0.25));
+        let gbm = GbmKernel {
+            spot,
+            rate,
+            vol,
+            dt,
+        };
+        let zs = [t(0.7), t(-1.1), t(0.0), t(2.0)];
+        let path = run(gbm, &zs.map(|z| [z]));
+        // log S_T = log S_0 + (r - σ²/2) T + σ √dt Σ z
+        let log_terminal = spot.ln() + (rate - t(0.02)) * t(1.0) + vol * dt.sqrt() * t(1.6);
+        assert_approx_eq!(path[3], log_terminal.exp());
+
+        // Deterministic variance at its mean level is GBM with σ = √θ, whatever z_var.
+        let heston = HestonKernel {
+            spot,
+            var0: t(0.04),
+            rate,
+            kappa: t(1.5),
+            theta: t(0.04),
+            xi: t(0.0),
+            rho: t(-0.7),
+            dt,
+            scheme: HestonScheme::FullTruncation,
+        };
+        for scheme in [
+            HestonScheme::FullTruncation,
+            HestonScheme::QuadraticExponential,
+        ] {
+            let heston_path = run(HestonKernel { scheme, ..heston }, &zs.map(|z| [z, t(0.3)]));
+            for (h, g) in heston_path.iter().zip(&path) {
+                assert_approx_eq!(*h, *g);
+            }
+        }
+
+        // QE matches the conditional mean of the variance.
+        let qe = HestonKernel {
+            xi: t(0.5),
+            var0: t(0.09),
+            scheme: HestonScheme::QuadraticExponential,
+            ..heston
+        };
+        let rng = Philox::new(1, 0);
+        let shocks = StandardNormals::<TradingFloat, 2>::new();
+        let n = 20_000;
+        let mean_var = (0..n)
+            .map(|i| {
+                let mut state = qe.init();
+                qe.step(&mut state, shocks.sample(&rng, i));
+                state.1.to_f64()
+            })
+            .sum::<f64>()
+            / n as f64;
+        let expected = 0.04 + (0.09 - 0.04) * (-1.5f64 * 0.25).exp();
+        assert!(
+            (mean_var - expected).abs() < 1e-3,
+            "{mean_var} vs {expected}"
+        );
+
+        // Without jumps Merton is GBM.
+        let merton = MertonKernel {
+            spot,
+            rate,
+            vol,
+            intensity: t(0.0),
+            jump_mean: t(-0.1),
+            jump_vol: t(0.15),
+            dt,
+        };
+        let merton_path = run(merton, &zs.map(|z| [z, t(3.0), t(-2.0)]));
+        for (m, g) in merton_path.iter().zip(&path) {
+            assert_approx_eq!(*m, *g);
+        }
+        let jumpy = MertonKernel {
+            intensity: t(2.0),
+            ..merton
+        };
+        // Φ(-3) is below P(N = 0) = e^-0.5; Φ(1) falls in the one-jump bucket.
+        assert_eq!(jumpy.jump_count(t(-3.0).norm_cdf()), 0);
+        assert_eq!(jumpy.jump_count(t(1.0).norm_cdf()), 1);
+
+        check_tangents(gbm, spot, &zs.map(|z| [z]));
+        check_tangents(qe, spot, &zs.map(|z| [z, -z]));
+        check_tangents(jumpy, spot, &zs.map(|z| [z, z, t(0.5)]));
+
+        // Antithetic shocks mirror the originals index by index.
+        let mirrored = shocks.antithetic();
+        assert_eq!(mirrored.sample(&rng, 7), shocks.sample(&rng, 7).map(|z| -z));
+
+        let call = CallPayoff { strike: t(100.0) };
+        let put = PutPayoff { strike: t(100.0) };
+        assert_eq!(
+            (call.apply(t(103.0)), put.apply(t(103.0))),
+            (t(3.0), t(0.0))
+        );
+        assert_eq!(
+            (call.derivative(t(97.0)), put.derivative(t(97.0))),
+            (t(0.0), t(-1.0))
+        );
+    }
+}
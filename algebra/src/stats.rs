@@ -0,0 +1,730 @@
+use super::{
+    Data, Lanes, Line, One, OrderFreeMerge, OrderedField, Promote, Real, ReduceKernel, zip_lanes,
+};
+use core::cmp::Ordering;
+
+// Statistical reductions. Every accumulator has an exact-order `merge`, so they compose
+// with `Pairwise` (and any backend that splits the input) without changing the result
+// beyond rounding.
+
+/// Welford's running moments: `m2` is the sum of squared deviations from the running mean.
+/// Updating the mean before the deviations avoids the cancellation of `E[x²] - E[x]²`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welford<F> {
+    pub count: u64,
+    pub mean: F,
+    pub m2: F,
+}
+
+impl<F: Real> Welford<F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: F::zero(),
+            m2: F::zero(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn push(self, x: F) -> Self {
+        let count = self.count + 1;
+        let delta = x - self.mean;
+        let mean = self.mean + delta / F::from_f64(count as f64);
+        Self {
+            count,
+            mean,
+            m2: self.m2 + delta * (x - mean),
+        }
+    }
+
+    // Chan et al.: the pairwise update for two disjoint partitions.
+    #[inline(always)]
+    pub fn merge(self, other: Self) -> Self {
+        if other.count == 0 {
+            return self;
+        }
+        if self.count == 0 {
+            return other;
+        }
+        let count = self.count + other.count;
+        let (n_a, n_b, n) = (
+            F::from_f64(self.count as f64),
+            F::from_f64(other.count as f64),
+            F::from_f64(count as f64),
+        );
+        let delta = other.mean - self.mean;
+        Self {
+            count,
+            mean: self.mean + delta * n_b / n,
+            m2: self.m2 + other.m2 + delta * delta * n_a * n_b / n,
+        }
+    }
+
+    // m2 / (count - ddof)
+    pub fn variance(self, ddof: u64) -> F {
+        assert!(
+            self.count > ddof,
+            "variance: {} samples with {} degrees of freedom removed",
+            self.count,
+            ddof
+        );
+        self.m2 / F::from_f64((self.count - ddof) as f64)
+    }
+}
+
+impl<F: Real> Default for Welford<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeanKernel;
+impl<In> ReduceKernel<In> for MeanKernel
+where
+    In: Promote<In>,
+    In::Output: Real,
+{
+    type Acc = Welford<In::Output>;
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        Welford::new()
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        acc.push(x.promote_left())
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        acc1.merge(acc2)
+    }
+
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        assert!(acc.count > 0, "mean of an empty reduction");
+        acc.mean
+    }
+}
+
+/// Variance with `ddof` degrees of freedom removed from the denominator:
+/// 0 for the population variance, 1 for the unbiased sample variance.
+#[derive(Debug, Clone, Copy)]
+pub struct VarianceKernel {
+    pub ddof: u64,
+}
+
+impl VarianceKernel {
+    pub fn population() -> Self {
+        Self { ddof: 0 }
+    }
+
+    pub fn sample() -> Self {
+        Self { ddof: 1 }
+    }
+}
+
+impl<In> ReduceKernel<In> for VarianceKernel
+where
+    In: Promote<In>,
+    In::Output: Real,
+{
+    type Acc = Welford<In::Output>;
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        Welford::new()
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        acc.push(x.promote_left())
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        acc1.merge(acc2)
+    }
+
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        acc.variance(self.ddof)
+    }
+}
+
+/// Square root of `VarianceKernel`.
+#[derive(Debug, Clone, Copy)]
+pub struct StdKernel {
+    pub ddof: u64,
+}
+
+impl StdKernel {
+    pub fn population() -> Self {
+        Self { ddof: 0 }
+    }
+
+    pub fn sample() -> Self {
+        Self { ddof: 1 }
+    }
+
+    fn variance(&self) -> VarianceKernel {
+        VarianceKernel { ddof: self.ddof }
+    }
+}
+
+// Same accumulator as `VarianceKernel`; only `finish` differs.
+impl<In> ReduceKernel<In> for StdKernel
+where
+    In: Promote<In>,
+    In::Output: Real,
+{
+    type Acc = Welford<In::Output>;
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        ReduceKernel::<In>::init(&self.variance())
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        self.variance().step(acc, x)
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        ReduceKernel::<In>::merge(&self.variance(), acc1, acc2)
+    }
+
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        ReduceKernel::<In>::finish(&self.variance(), acc).sqrt()
+    }
+}
+
+/// Running central moments up to the fourth: `m_k` is the sum of `(x - mean)^k`.
+/// `merge` is Pébay's pairwise update, so partials combine in any tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Moments<F> {
+    pub count: u64,
+    pub mean: F,
+    pub m2: F,
+    pub m3: F,
+    pub m4: F,
+}
+
+impl<F: Real> Moments<F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: F::zero(),
+            m2: F::zero(),
+            m3: F::zero(),
+            m4: F::zero(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn push(self, x: F) -> Self {
+        self.merge(Self {
+            count: 1,
+            mean: x,
+            ..Self::new()
+        })
+    }
+
+    pub fn merge(self, other: Self) -> Self {
+        if other.count == 0 {
+            return self;
+        }
+        if self.count == 0 {
+            return other;
+        }
+        let count = self.count + other.count;
+        let (a, b, n) = (
+            F::from_f64(self.count as f64),
+            F::from_f64(other.count as f64),
+            F::from_f64(count as f64),
+        );
+        let (three, four, six) = (F::from_f64(3.0), F::from_f64(4.0), F::from_f64(6.0));
+        let delta = other.mean - self.mean;
+        let d_n = delta / n;
+        let d2_ab_n = delta * d_n * a * b;
+        Self {
+            count,
+            mean: self.mean + d_n * b,
+            m2: self.m2 + other.m2 + d2_ab_n,
+            m3: self.m3
+                + other.m3
+                + d2_ab_n * d_n * (a - b)
+                + three * d_n * (a * other.m2 - b * self.m2),
+            m4: self.m4
+                + other.m4
+                + d2_ab_n * d_n * d_n * (a * a - a * b + b * b)
+                + six * d_n * d_n * (a * a * other.m2 + b * b * self.m2)
+                + four * d_n * (a * other.m3 - b * self.m3),
+        }
+    }
+
+    // Population estimator g1 = √n m3 / m2^(3/2)
+    pub fn skewness(self) -> F {
+        assert!(self.count > 0, "skewness of an empty reduction");
+        let n = F::from_f64(self.count as f64);
+        n.sqrt() * self.m3 / (self.m2 * self.m2.sqrt())
+    }
+
+    // Population estimator g2 = n m4 / m2² - 3, zero for a normal distribution
+    pub fn excess_kurtosis(self) -> F {
+        assert!(self.count > 0, "kurtosis of an empty reduction");
+        let n = F::from_f64(self.count as f64);
+        n * self.m4 / (self.m2 * self.m2) - F::from_f64(3.0)
+    }
+}
+
+impl<F: Real> Default for Moments<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! moment_kernel {
+    ($(#[$doc:meta])* $name:ident, $finish:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+        impl<In> ReduceKernel<In> for $name
+        where
+            In: Promote<In>,
+            In::Output: Real,
+        {
+            type Acc = Moments<In::Output>;
+            type Output = In::Output;
+
+            #[inline(always)]
+            fn init(&self) -> Self::Acc {
+                Moments::new()
+            }
+
+            #[inline(always)]
+            fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+                acc.push(x.promote_left())
+            }
+
+            fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+                acc1.merge(acc2)
+            }
+
+            fn finish(&self, acc: Self::Acc) -> Self::Output {
+                acc.$finish()
+            }
+        }
+    };
+}
+
+moment_kernel!(
+    /// Sample skewness (biased, as `scipy.stats.skew`); negative for a long left tail.
+    SkewnessKernel,
+    skewness
+);
+moment_kernel!(
+    /// Excess kurtosis (biased, as `scipy.stats.kurtosis`); positive for fat tails.
+    KurtosisKernel,
+    excess_kurtosis
+);
+
+// Order statistics cannot be merged in O(1): these kernels buffer every element
+// and select in O(n) once in `finish`.
+
+// Linear interpolation between the order statistics around rank (n - 1) q,
+// i.e. numpy's default "linear" method.
+fn quantile<F: Real>(mut xs: Vec<F>, q: f64) -> F {
+    assert!(!xs.is_empty(), "quantile of an empty reduction");
+    assert!(
+        (0.0..=1.0).contains(&q),
+        "quantile must be in [0, 1]: {}",
+        q
+    );
+    let rank = (xs.len() - 1) as f64 * q;
+    let lo = rank.floor() as usize;
+    let (_, &mut below, above) = xs.select_nth_unstable(lo);
+    match above.iter().min() {
+        Some(&next) if rank > lo as f64 => below + (next - below) * F::from_f64(rank - lo as f64),
+        _ => below,
+    }
+}
+
+macro_rules! buffered_kernel {
+    ($name:ident, |$self_:ident, $xs:ident| $finish:expr) => {
+        impl<In> ReduceKernel<In> for $name
+        where
+            In: Promote<In>,
+            In::Output: Real,
+        {
+            type Acc = Vec<In::Output>;
+            type Output = In::Output;
+
+            fn init(&self) -> Self::Acc {
+                Vec::new()
+            }
+
+            #[inline(always)]
+            fn step(&self, mut acc: Self::Acc, x: In) -> Self::Acc {
+                acc.push(x.promote_left());
+                acc
+            }
+
+            fn merge(&self, mut acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+                acc1.extend(acc2);
+                acc1
+            }
+
+            fn finish(&$self_, $xs: Self::Acc) -> Self::Output {
+                $finish
+            }
+        }
+    };
+}
+
+/// Exact `q`-quantile, `q` in [0, 1].
+#[derive(Debug, Clone, Copy)]
+pub struct QuantileKernel {
+    pub q: f64,
+}
+buffered_kernel!(QuantileKernel, |self, xs| quantile(xs, self.q));
+
+/// Exact median; the mean of the two middle elements for an even count.
+#[derive(Debug, Clone, Copy)]
+pub struct MedianKernel;
+buffered_kernel!(MedianKernel, |self, xs| quantile(xs, 0.5));
+
+/// Median absolute deviation from the median. Multiply by 1.4826 for a
+/// consistent estimate of the standard deviation under normality.
+#[derive(Debug, Clone, Copy)]
+pub struct MadKernel;
+buffered_kernel!(MadKernel, |self, xs| {
+    let median = quantile(xs.clone(), 0.5);
+    quantile(xs.into_iter().map(|x| (x - median).abs()).collect(), 0.5)
+});
+
+// Min/max have no identity in general, so the accumulator starts empty.
+// Comparisons follow `PartialOrd`: a NaN is never picked unless it comes first.
+macro_rules! extremum_kernel {
+    ($(#[$doc:meta])* $name:ident, $better:ident, $lanes:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+        impl OrderFreeMerge for $name {}
+        impl<In: Data + PartialOrd + Line> ReduceKernel<In> for $name {
+            type Acc = Option<In>;
+            type Output = In;
+
+            #[inline(always)]
+            fn init(&self) -> Self::Acc {
+                None
+            }
+
+            #[inline(always)]
+            fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+                match acc {
+                    Some(best) if !matches!(x.partial_cmp(&best), Some(Ordering::$better)) => {
+                        Some(best)
+                    }
+                    _ => Some(x),
+                }
+            }
+
+            fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+                match acc2 {
+                    Some(x) => self.step(acc1, x),
+                    None => acc1,
+                }
+            }
+
+            // An empty lane starts from the element itself, which never beats itself.
+            #[inline(always)]
+            fn step_lanes(&self, acc: Lanes<Self::Acc>, x: Lanes<In>) -> Lanes<Self::Acc> {
+                let acc = zip_lanes(acc, x, |acc, x| acc.unwrap_or(x));
+                In::$lanes(acc, x).map(Some)
+            }
+
+            fn finish(&self, acc: Self::Acc) -> Self::Output {
+                acc.expect(concat!(stringify!($name), ": empty reduction"))
+            }
+        }
+    };
+}
+
+extremum_kernel!(MinKernel, Less, min_lanes);
+extremum_kernel!(MaxKernel, Greater, max_lanes);
+
+/// Running extremum together with its position. `len` counts every element seen,
+/// so a right-hand partial can shift its index past the left one in `merge`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Indexed<T> {
+    pub best: Option<(T, usize)>,
+    pub len: usize,
+}
+
+macro_rules! arg_extremum_kernel {
+    ($(#[$doc:meta])* $name:ident, $better:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+        impl<In: Data + PartialOrd> ReduceKernel<In> for $name {
+            type Acc = Indexed<In>;
+            type Output = usize;
+
+            #[inline(always)]
+            fn init(&self) -> Self::Acc {
+                Indexed { best: None, len: 0 }
+            }
+
+            #[inline(always)]
+            fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+                let best = match acc.best {
+                    Some((best, i)) if !matches!(x.partial_cmp(&best), Some(Ordering::$better)) => {
+                        Some((best, i))
+                    }
+                    _ => Some((x, acc.len)),
+                };
+                Indexed { best, len: acc.len + 1 }
+            }
+
+            // Ties keep the left partial, i.e. the first occurrence. This needs partials over
+            // contiguous runs, so unlike `MinKernel` it is not `OrderFreeMerge`.
+            fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+                let best = match (acc1.best, acc2.best) {
+                    (Some((l, i)), Some((r, _)))
+                        if !matches!(r.partial_cmp(&l), Some(Ordering::$better)) =>
+                    {
+                        Some((l, i))
+                    }
+                    (l, None) => l,
+                    (_, Some((r, j))) => Some((r, acc1.len + j)),
+                };
+                Indexed { best, len: acc1.len + acc2.len }
+            }
+
+            fn finish(&self, acc: Self::Acc) -> Self::Output {
+                acc.best
+                    .expect(concat!(stringify!($name), ": empty reduction"))
+                    .1
+            }
+        }
+    };
+}
+
+arg_extremum_kernel!(
+    /// Index of the first minimum.
+    ArgMinKernel,
+    Less
+);
+arg_extremum_kernel!(
+    /// Index of the first maximum.
+    ArgMaxKernel,
+    Greater
+);
+
+/// `ln Σ e^x` without overflow. The accumulator holds the running maximum `m` and
+/// `Σ e^(x - m)`, rescaled whenever the maximum moves.
+#[derive(Debug, Clone, Copy)]
+pub struct LogSumExpKernel;
+impl<In> ReduceKernel<In> for LogSumExpKernel
+where
+    In: Promote<In>,
+    In::Output: Real,
+{
+    type Acc = Option<(In::Output, In::Output)>;
+    type Output = In::Output;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Acc {
+        None
+    }
+
+    #[inline(always)]
+    fn step(&self, acc: Self::Acc, x: In) -> Self::Acc {
+        ReduceKernel::<In>::merge(self, acc, Some((x.promote_left(), In::Output::one())))
+    }
+
+    fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc {
+        match (acc1, acc2) {
+            // Equal maxima need no rescaling, and must not get it: for two infinite
+            // maxima `m2 - m1` is NaN.
+            (Some((m1, s1)), Some((m2, s2))) => Some(if m1 == m2 {
+                (m1, s1 + s2)
+            } else if m1 > m2 {
+                (m1, s1 + s2 * (m2 - m1).exp())
+            } else {
+                (m2, s1 * (m1 - m2).exp() + s2)
+            }),
+            (acc, None) | (None, acc) => acc,
+        }
+    }
+
+    fn finish(&self, acc: Self::Acc) -> Self::Output {
+        let (max, sum) = acc.expect("LogSumExpKernel: empty reduction");
+        max + sum.ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DoubleDouble, LANES, Tolerance, TradingFloat, assert_approx_eq};
+
+    // Sequential fold, and the same input folded in two halves and merged
+    fn reduce<K: ReduceKernel<TradingFloat>>(
+        kernel: &K,
+        xs: &[f64],
+        split: usize,
+    ) -> [K::Output; 2] {
+        let fold = |xs: &[f64]| {
+            xs.iter().fold(kernel.init(), |acc, &x| {
+                kernel.step(acc, TradingFloat::new(x))
+            })
+        };
+        let (l, r) = xs.split_at(split);
+        [
+            kernel.finish(fold(xs)),
+            kernel.finish(kernel.merge(fold(l), fold(r))),
+        ]
+    }
+
+    #[test]
+    fn test_statistical_kernels() {
+        let tf = TradingFloat::new;
+        // A large offset ruins E[x²] - E[x]² in f64; Welford keeps it exact.
+        let xs = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        for split in 0..=xs.len() {
+            let [seq, merged] = reduce(&MeanKernel, &xs, split);
+            assert_eq!((seq, merged), (tf(1e9 + 10.0), tf(1e9 + 10.0)));
+            for v in reduce(&VarianceKernel::population(), &xs, split) {
+                assert_approx_eq!(v, tf(22.5));
+            }
+            for v in reduce(&VarianceKernel::sample(), &xs, split) {
+                assert_approx_eq!(v, tf(30.0));
+            }
+            for v in reduce(&StdKernel::sample(), &xs, split) {
+                assert_approx_eq!(v, tf(30.0).sqrt());
+            }
+        }
+
+        // Ties resolve to the first occurrence, also across a merge.
+        let ys = [3.0, -1.0, 5.0, -1.0, 5.0];
+        for split in 0..=ys.len() {
+            assert_eq!(reduce(&MinKernel, &ys, split), [tf(-1.0); 2]);
+            assert_eq!(reduce(&MaxKernel, &ys, split), [tf(5.0); 2]);
+            assert_eq!(reduce(&ArgMinKernel, &ys, split), [1, 1]);
+            assert_eq!(reduce(&ArgMaxKernel, &ys, split), [2, 2]);
+        }
+
+        // Would overflow as exp(1000) summed directly.
+        let zs = [1000.0, 999.0, -5.0, 1000.0];
+        let exact = tf(1000.0) + (tf(2.0) + tf(-1.0).exp() + tf(-1005.0).exp()).ln();
+        for split in 0..=zs.len() {
+            for v in reduce(&LogSumExpKernel, &zs, split) {
+                assert_approx_eq!(v, exact);
+            }
+        }
+
+        // Partials whose maxima are the same infinity merge without rescaling.
+        let dd = DoubleDouble::from_f64;
+        let lse = |acc1, acc2| ReduceKernel::<DoubleDouble>::merge(&LogSumExpKernel, acc1, acc2);
+        for inf in [f64::INFINITY, f64::NEG_INFINITY] {
+            let partial = Some((dd(inf), dd(1.0)));
+            assert_eq!(lse(partial, partial), Some((dd(inf), dd(2.0))));
+        }
+    }
+
+    // Lane steps pick what the scalar step picks on every lane, NaN and signed zeros included.
+    fn check_lanes<K: ReduceKernel<f64, Acc = Option<f64>>>(kernel: K, xs: &[f64]) {
+        let lanes = xs
+            .chunks_exact(LANES)
+            .fold([kernel.init(); LANES], |acc, chunk| {
+                kernel.step_lanes(acc, chunk.try_into().unwrap())
+            });
+        for (lane, acc) in lanes.iter().enumerate() {
+            let scalar = xs
+                .iter()
+                .skip(lane)
+                .step_by(LANES)
+                .fold(kernel.init(), |acc, &x| kernel.step(acc, x));
+            assert_eq!(
+                acc.map(f64::to_bits),
+                scalar.map(f64::to_bits),
+                "lane {lane}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extremum_lanes() {
+        let nan = f64::NAN;
+        let xs: [Lanes<f64>; 3] = [
+            [3.0, nan, -1.0, 2.0, 0.0, -0.0, 7.5, f64::INFINITY],
+            [1.0, 5.0, nan, -2.0, -0.0, 0.0, 7.5, -3.0],
+            [nan, 4.0, -9.0, 2.0, 1.0, -1.0, nan, f64::NEG_INFINITY],
+        ];
+        let xs = xs.as_flattened();
+        check_lanes(MinKernel, xs);
+        check_lanes(MaxKernel, xs);
+    }
+
+    #[test]
+    fn test_higher_moments_and_order_statistics() {
+        let tf = TradingFloat::new;
+        let xs = [0.02, -0.01, 0.005, -0.08, 0.011, 0.003, 0.04, -0.002, 0.0];
+
+        // Two-pass reference
+        let n = xs.len() as f64;
+        let mean = xs.iter().sum::<f64>() / n;
+        let central = |k: i32| xs.iter().map(|x| (x - mean).powi(k)).sum::<f64>() / n;
+        let skew = central(3) / central(2).powf(1.5);
+        let kurt = central(4) / (central(2) * central(2)) - 3.0;
+        for split in 0..=xs.len() {
+            for v in reduce(&SkewnessKernel, &xs, split) {
+                assert_approx_eq!(v, tf(skew), Tolerance::Absolute(1e-12));
+            }
+            for v in reduce(&KurtosisKernel, &xs, split) {
+                assert_approx_eq!(v, tf(kurt), Tolerance::Absolute(1e-12));
+            }
+        }
+
+        // Sorted: -0.08 -0.01 -0.002 0 0.003 0.005 0.011 0.02 0.04
+        for split in [0, 4, 9] {
+            assert_eq!(reduce(&MedianKernel, &xs, split), [tf(0.003); 2]);
+            assert_eq!(
+                reduce(&QuantileKernel { q: 0.0 }, &xs, split),
+                [tf(-0.08); 2]
+            );
+            assert_eq!(
+                reduce(&QuantileKernel { q: 1.0 }, &xs, split),
+                [tf(0.04); 2]
+            );
+            // Rank 8 * 0.3 = 2.4: 0.6 of -0.002 and 0.4 of 0
+            for v in reduce(&QuantileKernel { q: 0.3 }, &xs, split) {
+                assert_approx_eq!(v, tf(-0.0012));
+            }
+            // |x - 0.003|, sorted: 0 .002 .003 .005 .008 .013 .017 .037 .083
+            for v in reduce(&MadKernel, &xs, split) {
+                assert_approx_eq!(v, tf(0.008));
+            }
+        }
+        assert_eq!(
+            reduce(&MedianKernel, &[4.0, 1.0, 3.0, 2.0], 2),
+            [tf(2.5); 2]
+        );
+        // Out-of-range levels fail loudly in every build, not as an index error or the minimum.
+        for q in [-0.1, 1.1, f64::NAN] {
+            let quantile = std::panic::catch_unwind(|| reduce(&QuantileKernel { q }, &xs, 0));
+            assert!(quantile.is_err());
+        }
+
+        // A single outlier moves the standard deviation, not the MAD.
+        let mut noisy = xs;
+        noisy[0] = 5.0;
+        assert_eq!(reduce(&MadKernel, &noisy, 0), reduce(&MadKernel, &xs, 0));
+        assert!(reduce(&StdKernel::population(), &noisy, 0)[0] > tf(1.0));
+    }
+}
@@ -199,6 +199,15 @@ pub trait AddUniqueImpl<L, Flag: Bool> {
     type Output: Shape;
 }
 
+impl<L, List> AddUnique<L> for List
+where
+    List: Shape + Contains<L>,
+    <List as Contains<L>>::Result: Bool,
+    List: AddUniqueImpl<L, <List as Contains<L>>::Result>,
+{
+    type Output = <List as AddUniqueImpl<L, <List as Contains<L>>::Result>>::Output;
+}
+
 impl<L, List> AddUniqueImpl<L, True> for List
 where
     List: Shape,
@@ -476,6 +485,8 @@ where
         <<L as RemoveAll<Shared>>::Remainder as Union<<R as RemoveAll<Shared>>::Remainder>>::Output;
 }
 
+pub type Contracted<L, R, Shared> = <() as ContractShape<L, R, Shared>>::Output;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __generate_inequality {
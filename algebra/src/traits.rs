@@ -1,5 +1,6 @@
+use super::Lanes;
 use core::cmp::Ord;
-use core::fmt::Debug;
+use core::fmt::{self, Debug, Display};
 use core::iter::{Product, Sum};
 use core::ops::{Add, Div, Mul, Neg, Sub};
 
@@ -50,6 +51,168 @@ pub trait Real: OrderedField {
     fn pow(self, exp: Self) -> Self;
     fn sin(self) -> Self;
     fn cos(self) -> Self;
+    // Angle of the point (other, self) in (-π, π]
+    fn atan2(self, other: Self) -> Self;
+    // Nearest representable value; used for literals in generic code
+    fn from_f64(x: f64) -> Self;
+
+    // Everything below has a generic default built from the methods above.
+    // Scalars backed by a math library override them for speed and accuracy.
+    fn sinh(self) -> Self {
+        (self.exp() - (-self).exp()) / (Self::one() + Self::one())
+    }
+    fn cosh(self) -> Self {
+        (self.exp() + (-self).exp()) / (Self::one() + Self::one())
+    }
+    // Through e^(-2|x|), which never overflows
+    fn tanh(self) -> Self {
+        let t = (-(self.abs() + self.abs())).exp();
+        self.signum() * (Self::one() - t) / (Self::one() + t)
+    }
+    fn atan(self) -> Self {
+        self.atan2(Self::one())
+    }
+    // ln(1 + x), accurate for small x where the scalar overrides it
+    fn log1p(self) -> Self {
+        (Self::one() + self).ln()
+    }
+    // e^x - 1, accurate for small x where the scalar overrides it
+    fn expm1(self) -> Self {
+        self.exp() - Self::one()
+    }
+    fn log2(self) -> Self {
+        self.ln() / (Self::one() + Self::one()).ln()
+    }
+    fn log10(self) -> Self {
+        self.ln() / Self::from_f64(10.0).ln()
+    }
+    fn powi(self, n: i32) -> Self {
+        crate::special::powi(self, n)
+    }
+
+    // Special functions
+    fn erf(self) -> Self {
+        crate::special::erf(self)
+    }
+    fn erfc(self) -> Self {
+        crate::special::erfc(self)
+    }
+    // Standard normal density
+    fn norm_pdf(self) -> Self {
+        crate::special::norm_pdf(self)
+    }
+    // Standard normal distribution function
+    fn norm_cdf(self) -> Self {
+        crate::special::norm_cdf(self)
+    }
+    // Quantile of the standard normal, defined on (0, 1)
+    fn norm_inv_cdf(self) -> Self {
+        crate::special::norm_inv_cdf(self)
+    }
+    // ln|Γ(x)|
+    fn lgamma(self) -> Self {
+        crate::special::lgamma(self)
+    }
+    fn gamma(self) -> Self {
+        crate::special::gamma(self)
+    }
+}
+
+// Arithmetic failures surfaced by checked operations instead of Inf/NaN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArithmeticError {
+    DivisionByZero,
+    // Result magnitude is not representable
+    Overflow,
+    // Argument outside the domain of the function (e.g. ln of a negative number)
+    Domain,
+}
+
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::DivisionByZero => write!(f, "division by zero"),
+            ArithmeticError::Overflow => write!(f, "arithmetic overflow"),
+            ArithmeticError::Domain => write!(f, "argument outside function domain"),
+        }
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+pub trait CheckedArith: Sized {
+    fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError>;
+    fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError>;
+    fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError>;
+    fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError>;
+    fn checked_rem(self, rhs: Self) -> Result<Self, ArithmeticError>;
+    fn checked_neg(self) -> Result<Self, ArithmeticError>;
+    fn checked_recip(self) -> Result<Self, ArithmeticError>;
+}
+
+// Tolerance for approximate comparisons
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    // |a - b| <= eps
+    Absolute(f64),
+    // |a - b| <= rel * max(|a|, |b|)
+    Relative(f64),
+    // At most n representable values apart (in the scalar's own precision)
+    Ulps(u64),
+}
+
+impl Tolerance {
+    // A few roundings of slack, independent of magnitude and precision.
+    pub const DEFAULT: Self = Tolerance::Ulps(4);
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// Equality up to floating point drift. `PartialEq` stays exact so hashing remains sound.
+// Non-finite values never compare approximately equal.
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+    fn abs_diff_eq(&self, other: &Rhs, epsilon: f64) -> bool;
+    fn relative_eq(&self, other: &Rhs, max_relative: f64) -> bool;
+    fn ulps_eq(&self, other: &Rhs, max_ulps: u64) -> bool;
+
+    fn approx_eq(&self, other: &Rhs, tol: Tolerance) -> bool {
+        match tol {
+            Tolerance::Absolute(epsilon) => self.abs_diff_eq(other, epsilon),
+            Tolerance::Relative(max_relative) => self.relative_eq(other, max_relative),
+            Tolerance::Ulps(max_ulps) => self.ulps_eq(other, max_ulps),
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::Tolerance::DEFAULT)
+    };
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {{
+        let (left, right, tol) = (&$left, &$right, $tol);
+        if !$crate::ApproxEq::approx_eq(left, right, tol) {
+            panic!(
+                "assertion `left ≈ right` failed ({:?})\n  left: {:?}\n right: {:?}",
+                tol, left, right
+            );
+        }
+    }};
+}
+
+// Neighbouring representable values, used for outward rounding
+pub trait NextFloat: Sized {
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
+}
+
+// Rounding conversion into a narrower storage type; the inverse direction of `Promote`.
+pub trait Narrow<T>: Sized {
+    fn narrow(self) -> T;
 }
 
 pub trait Discretization: Sized {
@@ -66,24 +229,76 @@ pub trait UnaryKernel<In>: KernelBase {
     type Output: Data;
 
     fn apply(&self, x: In) -> Self::Output;
+
+    // One chunk of a contiguous run. Backends call this on every full chunk and `apply` on
+    // the tail; kernels with a `Line` form override it, everything else loops.
+    #[inline(always)]
+    fn apply_lanes(&self, x: Lanes<In>) -> Lanes<Self::Output> {
+        x.map(|x| self.apply(x))
+    }
+}
+
+// Element-wise derivative, the local factor of the chain rule: grad_in = f'(x) * grad_out.
+// Kernels without a useful derivative (steps, signs) return a surrogate instead.
+pub trait DifferentiableKernel<F>: UnaryKernel<F, Output = F> {
+    fn derivative(&self, x: F) -> F;
 }
 
 pub trait BinaryKernel<L, R>: KernelBase {
     type Output: Data;
 
     fn apply(&self, lhs: L, rhs: R) -> Self::Output;
+
+    #[inline(always)]
+    fn apply_lanes(&self, lhs: Lanes<L>, rhs: Lanes<R>) -> Lanes<Self::Output> {
+        let mut rhs = rhs.into_iter();
+        lhs.map(|l| self.apply(l, rhs.next().unwrap()))
+    }
+}
+
+// Order in which a backend folds the elements of a reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReduceOrder {
+    // Left-to-right fold, rounding error grows O(n)
+    #[default]
+    Sequential,
+    // Balanced tree over leaf blocks of `block` elements, rounding error grows O(log n)
+    Pairwise {
+        block: usize,
+    },
+    // `LANES` interleaved partials (lane `i` folds elements `i, i + LANES, ...`) merged at
+    // the end. Only for kernels whose `merge` ignores where a partial came from
+    // (`OrderFreeMerge`).
+    Lanes,
 }
 
 pub trait ReduceKernel<In>: KernelBase {
     type Output: Data;
-    type Acc: Data;
+    // Usually a small `Copy` value; buffering kernels (e.g. quantiles) hold a `Vec`.
+    type Acc: Clone + Debug;
 
     fn init(&self) -> Self::Acc;
     fn step(&self, acc: Self::Acc, x: In) -> Self::Acc;
     fn merge(&self, acc1: Self::Acc, acc2: Self::Acc) -> Self::Acc;
     fn finish(&self, acc: Self::Acc) -> Self::Output;
+
+    // One `step` per lane, used under `ReduceOrder::Lanes`.
+    #[inline(always)]
+    fn step_lanes(&self, acc: Lanes<Self::Acc>, x: Lanes<In>) -> Lanes<Self::Acc> {
+        let mut x = x.into_iter();
+        acc.map(|acc| self.step(acc, x.next().unwrap()))
+    }
+
+    // The backend calls `merge` with the left partial first, so `merge` may rely on order.
+    fn order(&self) -> ReduceOrder {
+        ReduceOrder::Sequential
+    }
 }
 
+// Reductions whose `merge` does not care which elements each partial covered, so
+// `Lanewise` may interleave them. Position-tracking kernels (ArgMin, ArgMax) are not.
+pub trait OrderFreeMerge {}
+
 pub trait StreamKernel<In>: KernelBase {
     type State: Clone + Debug;
     type Output: Data;
@@ -92,6 +307,20 @@ pub trait StreamKernel<In>: KernelBase {
     fn step(&self, state: &mut Self::State, input: In) -> Self::Output;
 }
 
+// Pathwise derivative of a stream by the value its state starts from, e.g. the spot of a
+// simulated path: the tangent is d state / d start, stepped forward alongside the state.
+pub trait DifferentiableStreamKernel<In>: StreamKernel<In> {
+    fn init_tangent(&self) -> Self::State;
+
+    // Steps `state` and `tangent` together and returns the output with d output / d start.
+    fn step_tangent(
+        &self,
+        state: &mut Self::State,
+        tangent: &mut Self::State,
+        input: In,
+    ) -> (Self::Output, Self::Output);
+}
+
 pub trait Promote<Rhs>: Data {
     type Output: Data;
 
@@ -0,0 +1,194 @@
+use super::{ApproxEq, One, Promote, Semiring, Zero};
+use core::cmp::Ordering;
+use core::fmt::{self, Display};
+use core::iter::{Product, Sum};
+use core::ops::{Add, Mul};
+
+// Both semirings store the infinite element as `None`, so any `F` (including
+// scalars without an infinity such as `TradingFloat` or `Decimal`) can be lifted.
+macro_rules! tropical {
+    (
+        $(#[$doc:meta])*
+        $name:ident, pick = $pick:path, inf = $inf:ident, inf_vs_finite = $inf_ord:ident, $inf_str:literal
+    ) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name<F>(Option<F>);
+
+        impl<F> $name<F> {
+            pub const $inf: Self = Self(None);
+
+            pub const fn new(value: F) -> Self {
+                Self(Some(value))
+            }
+
+            // `None` for the infinite element
+            pub fn value(self) -> Option<F> {
+                self.0
+            }
+
+            pub fn is_finite(&self) -> bool {
+                self.0.is_some()
+            }
+        }
+
+        impl<F> From<F> for $name<F> {
+            fn from(value: F) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<F: Ord> Ord for $name<F> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                match (&self.0, &other.0) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) => Ordering::$inf_ord,
+                    (Some(_), None) => Ordering::$inf_ord.reverse(),
+                    (Some(a), Some(b)) => a.cmp(b),
+                }
+            }
+        }
+
+        impl<F: Ord> PartialOrd for $name<F> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<F: Display> Display for $name<F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match &self.0 {
+                    Some(value) => value.fmt(f),
+                    None => f.write_str($inf_str),
+                }
+            }
+        }
+
+        impl<F: Semiring + Ord> Add for $name<F> {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $pick(self, rhs)
+            }
+        }
+
+        // The infinite element absorbs: it is the annihilator of `*`.
+        impl<F: Semiring + Ord> Mul for $name<F> {
+            type Output = Self;
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn mul(self, rhs: Self) -> Self {
+                match (self.0, rhs.0) {
+                    (Some(a), Some(b)) => Self::new(a + b),
+                    _ => Self::$inf,
+                }
+            }
+        }
+
+        impl<F: Semiring + Ord> Sum for $name<F> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<F: Semiring + Ord> Product for $name<F> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl<F> Zero for $name<F> {
+            #[inline]
+            fn zero() -> Self {
+                Self::$inf
+            }
+        }
+
+        impl<F: Zero> One for $name<F> {
+            #[inline]
+            fn one() -> Self {
+                Self::new(F::zero())
+            }
+        }
+
+        impl<F: Semiring + Ord> Semiring for $name<F> {}
+
+        // Infinities compare equal to each other and to nothing else
+        impl<F: ApproxEq> ApproxEq for $name<F> {
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                match (&self.0, &other.0) {
+                    (Some(a), Some(b)) => a.abs_diff_eq(b, epsilon),
+                    (a, b) => a.is_none() && b.is_none(),
+                }
+            }
+
+            fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+                match (&self.0, &other.0) {
+                    (Some(a), Some(b)) => a.relative_eq(b, max_relative),
+                    (a, b) => a.is_none() && b.is_none(),
+                }
+            }
+
+            fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool {
+                match (&self.0, &other.0) {
+                    (Some(a), Some(b)) => a.ulps_eq(b, max_ulps),
+                    (a, b) => a.is_none() && b.is_none(),
+                }
+            }
+        }
+
+        impl<F: Semiring + Ord> Promote<$name<F>> for $name<F> {
+            type Output = $name<F>;
+
+            fn promote_left(self) -> Self::Output {
+                self
+            }
+
+            fn promote_right(rhs: $name<F>) -> Self::Output {
+                rhs
+            }
+        }
+    };
+}
+
+tropical!(
+    /// MaxPlus is the (max, +) semiring: `a + b = max(a, b)`, `a * b = a + b`,
+    /// with `zero() = -∞` and `one() = 0`. Sums are best-path scores, so a
+    /// `SumKernel` reduction is a maximum and a contraction is a Viterbi step.
+    MaxPlus, pick = core::cmp::max, inf = NEG_INFINITY, inf_vs_finite = Less, "-inf"
+);
+
+tropical!(
+    /// MinPlus is the (min, +) semiring: `a + b = min(a, b)`, `a * b = a + b`,
+    /// with `zero() = +∞` and `one() = 0`. Contracting an edge-cost matrix with
+    /// itself relaxes every path by one hop (shortest paths).
+    MinPlus, pick = core::cmp::min, inf = INFINITY, inf_vs_finite = Greater, "inf"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TradingFloat;
+
+    #[test]
+    fn test_tropical_identities() {
+        let t = |x: f64| MaxPlus::new(TradingFloat::new(x));
+        let xs = [t(1.0), t(-3.0), t(2.5)];
+
+        assert_eq!(xs.iter().copied().sum::<MaxPlus<_>>(), t(2.5));
+        assert_eq!(xs.iter().copied().product::<MaxPlus<_>>(), t(0.5));
+        assert_eq!(t(4.0) + MaxPlus::zero(), t(4.0));
+        assert_eq!(t(4.0) * MaxPlus::one(), t(4.0));
+        assert_eq!(t(4.0) * MaxPlus::zero(), MaxPlus::NEG_INFINITY);
+        assert!(MaxPlus::NEG_INFINITY < t(f64::MIN));
+
+        let costs = [MinPlus::new(3_i64), MinPlus::INFINITY, MinPlus::new(-1)];
+        assert_eq!(
+            costs.iter().copied().sum::<MinPlus<i64>>(),
+            MinPlus::new(-1)
+        );
+        assert_eq!(MinPlus::<i64>::zero() + MinPlus::new(7), MinPlus::new(7));
+        assert!(MinPlus::INFINITY > MinPlus::new(i64::MAX));
+        assert_eq!(MinPlus::<i64>::INFINITY.to_string(), "inf");
+    }
+}
@@ -1,5 +1,9 @@
 use super::{Backend, Storage, UnifiedStorage};
-use algebra::{BinaryKernel, Data, ReduceKernel, StreamKernel, UnaryKernel};
+use algebra::{
+    BinaryKernel, BrownianBridge, CounterRng, Data, DifferentiableStreamKernel, DistanceMetric,
+    Distribution, LANES, Lanes, LowDiscrepancy, Real, ReduceKernel, ReduceOrder, Semiring,
+    StreamKernel, UnaryKernel,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct GenericBackend;
@@ -101,7 +105,7 @@ impl Backend for GenericBackend {
                 // Heap allocation in a hot path
                 let mut idx = vec![0; rank];
 
-                for linear in 0..numel {
+                for dst in dst_slice.iter_mut() {
                     let mut src_idx = offset;
 
                     match rank {
@@ -117,7 +121,7 @@ impl Backend for GenericBackend {
                         }
                     }
 
-                    dst_slice[linear] = src_slice[src_idx];
+                    *dst = src_slice[src_idx];
 
                     for i in (0..rank).rev() {
                         idx[i] += 1;
@@ -142,12 +146,16 @@ impl Backend for GenericBackend {
         K::Output: Data,
     {
         let mut output = UnifiedStorage::<K::Output>::alloc(input.len());
-        let input_slice = input.as_slice();
-        let output_slice = output.as_mut_slice();
+        let input_chunks = input.as_slice().chunks_exact(LANES);
+        let input_tail = input_chunks.remainder();
+        let mut output_chunks = output.as_mut_slice().chunks_exact_mut(LANES);
 
         // TODO: Parallelism
-        for i in 0..input.len() {
-            output_slice[i] = kernel.apply(input_slice[i]);
+        for (src, dst) in input_chunks.zip(&mut output_chunks) {
+            dst.copy_from_slice(&kernel.apply_lanes(lanes(src)));
+        }
+        for (&x, out) in input_tail.iter().zip(output_chunks.into_remainder()) {
+            *out = kernel.apply(x);
         }
 
         output
@@ -164,61 +172,523 @@ impl Backend for GenericBackend {
     {
         debug_assert_eq!(lhs.len(), rhs.len());
         let mut output = UnifiedStorage::<K::Output>::alloc(lhs.len());
-        let lhs_slice = lhs.as_slice();
-        let rhs_slice = rhs.as_slice();
-        let output_slice = output.as_mut_slice();
+        let lhs_chunks = lhs.as_slice().chunks_exact(LANES);
+        let rhs_chunks = rhs.as_slice().chunks_exact(LANES);
+        let (lhs_tail, rhs_tail) = (lhs_chunks.remainder(), rhs_chunks.remainder());
+        let mut output_chunks = output.as_mut_slice().chunks_exact_mut(LANES);
 
-        for i in 0..lhs.len() {
-            output_slice[i] = kernel.apply(lhs_slice[i], rhs_slice[i]);
+        for ((l, r), dst) in lhs_chunks.zip(rhs_chunks).zip(&mut output_chunks) {
+            dst.copy_from_slice(&kernel.apply_lanes(lanes(l), lanes(r)));
+        }
+        for ((&l, &r), out) in lhs_tail
+            .iter()
+            .zip(rhs_tail)
+            .zip(output_chunks.into_remainder())
+        {
+            *out = kernel.apply(l, r);
         }
 
         output
     }
 
-    fn stream<I: Data, K: StreamKernel<I>>(
+    fn select<T: Data>(
+        &mut self,
+        mask: &Self::Storage<bool>,
+        then_: &Self::Storage<T>,
+        else_: &Self::Storage<T>,
+    ) -> Self::Storage<T> {
+        debug_assert!(mask.len() == then_.len() && mask.len() == else_.len());
+        let mut output = UnifiedStorage::<T>::alloc(mask.len());
+
+        // Indexing by the mask bit compiles to a conditional move / blend.
+        for (((&m, &t), &e), out) in mask
+            .as_slice()
+            .iter()
+            .zip(then_.as_slice())
+            .zip(else_.as_slice())
+            .zip(output.as_mut_slice())
+        {
+            *out = [e, t][m as usize];
+        }
+
+        output
+    }
+
+    fn grouped<P: Data, I: Data, K: BinaryKernel<P, I>>(
         &mut self,
+        params: &Self::Storage<P>,
         input: &Self::Storage<I>,
+        inner: usize,
         kernel: K,
     ) -> Self::Storage<K::Output>
     where
         K::Output: Data,
     {
         let mut output = UnifiedStorage::<K::Output>::alloc(input.len());
-        let input_slice = input.as_slice();
-        let output_slice = output.as_mut_slice();
-        let mut state = kernel.init();
-        // TODO: Serial Bottleneck.
-        // Stream kernels (like EMA/RNN) are inherently serial.
-        // However, if we have a batch of independent streams (e.g., [Batch, Time]),
-        // we should parallelize across the Batch dimension.
-        // Currently, this assumes a flat 1D stream.
-        for i in 0..input.len() {
-            output_slice[i] = kernel.step(&mut state, input_slice[i]);
+        if input.is_empty() {
+            return output;
+        }
+        debug_assert!(!params.is_empty() && inner > 0);
+        let params_slice = params.as_slice();
+
+        for (g, (src, dst)) in input
+            .as_slice()
+            .chunks(inner)
+            .zip(output.as_mut_slice().chunks_mut(inner))
+            .enumerate()
+        {
+            let p = params_slice[g % params_slice.len()];
+            for (&x, out) in src.iter().zip(dst) {
+                *out = kernel.apply(p, x);
+            }
         }
 
         output
     }
 
-    fn reduce<I: Data, K: ReduceKernel<I>>(
+    fn stream<I: Data, K: StreamKernel<I>>(
         &mut self,
         input: &Self::Storage<I>,
+        len: usize,
         kernel: K,
     ) -> Self::Storage<K::Output>
     where
         K::Output: Data,
     {
-        let input_slice = input.as_slice();
-        let mut acc = kernel.init();
+        let mut output = UnifiedStorage::<K::Output>::alloc(input.len());
+        if input.is_empty() {
+            return output;
+        }
+        debug_assert_eq!(input.len() % len, 0);
 
-        // TODO: Tree Reduction (Map-Reduce).
-        // Serial reduction is limited by single-core speed.
-        for &val in input_slice {
-            acc = kernel.step(acc, val);
+        // TODO: Parallelism.
+        // Each row is serial, but rows (e.g. simulated paths) are independent.
+        for (src, dst) in input
+            .as_slice()
+            .chunks(len)
+            .zip(output.as_mut_slice().chunks_mut(len))
+        {
+            let mut state = kernel.init();
+            for (&x, out) in src.iter().zip(dst) {
+                *out = kernel.step(&mut state, x);
+            }
         }
 
+        output
+    }
+
+    fn stream_pullback<I: Data, K: DifferentiableStreamKernel<I>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        grad: &Self::Storage<K::Output>,
+        len: usize,
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Semiring,
+    {
+        if input.is_empty() {
+            return UnifiedStorage::alloc(0);
+        }
+        debug_assert_eq!(input.len() % len, 0);
+        debug_assert_eq!(input.len(), grad.len());
+
+        // Replays each row with its tangent rather than storing the states of the forward pass.
+        let mut output = UnifiedStorage::<K::Output>::alloc(input.len() / len);
+        for ((src, grad), out) in input
+            .as_slice()
+            .chunks(len)
+            .zip(grad.as_slice().chunks(len))
+            .zip(output.as_mut_slice())
+        {
+            let (mut state, mut tangent) = (kernel.init(), kernel.init_tangent());
+            *out = src
+                .iter()
+                .zip(grad)
+                .map(|(&x, &g)| g * kernel.step_tangent(&mut state, &mut tangent, x).1)
+                .sum();
+        }
+
+        output
+    }
+
+    fn scan<I: Data, A: Data, K: BinaryKernel<A, I, Output = A>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        len: usize,
+        init: A,
+        kernel: K,
+    ) -> Self::Storage<A> {
+        let mut output = UnifiedStorage::<A>::alloc(input.len());
+        if input.is_empty() {
+            return output;
+        }
+        debug_assert_eq!(input.len() % len, 0);
+
+        // TODO: Parallelism.
+        // Rows are independent; a parallel prefix (Blelloch) would also split long rows.
+        for (src, dst) in input
+            .as_slice()
+            .chunks(len)
+            .zip(output.as_mut_slice().chunks_mut(len))
+        {
+            let mut acc = init;
+            for (&x, out) in src.iter().zip(dst) {
+                acc = kernel.apply(acc, x);
+                *out = acc;
+            }
+        }
+
+        output
+    }
+
+    fn reduce<I: Data, K: ReduceKernel<I>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Data,
+    {
+        let acc = fold_ordered(&kernel, input.as_slice());
+
         let mut out = UnifiedStorage::<K::Output>::alloc(1);
         out.as_mut_slice()[0] = kernel.finish(acc);
 
         out
     }
+
+    fn reduce_axis<I: Data, K: ReduceKernel<I>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        dims: [usize; 3],
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Data,
+    {
+        let [outer, len, inner] = dims;
+        debug_assert_eq!(input.len(), outer * len * inner);
+
+        let mut output = UnifiedStorage::<K::Output>::alloc(outer * inner);
+        let input_slice = input.as_slice();
+        let output_slice = output.as_mut_slice();
+
+        // Lanes along a non-last axis are strided; gather each one so the kernel's
+        // fold order applies unchanged.
+        let mut lane = Vec::with_capacity(len);
+        for o in 0..outer {
+            let block = &input_slice[o * len * inner..(o + 1) * len * inner];
+            for i in 0..inner {
+                let acc = if inner == 1 {
+                    fold_ordered(&kernel, block)
+                } else {
+                    lane.clear();
+                    lane.extend(block.iter().skip(i).step_by(inner));
+                    fold_ordered(&kernel, &lane)
+                };
+                output_slice[o * inner + i] = kernel.finish(acc);
+            }
+        }
+
+        output
+    }
+
+    fn contract<L: Data, R: Data, M: BinaryKernel<L, R>, K: ReduceKernel<M::Output>>(
+        &mut self,
+        lhs: &Self::Storage<L>,
+        rhs: &Self::Storage<R>,
+        dims: [usize; 4],
+        mul: M,
+        reduce: K,
+    ) -> Self::Storage<K::Output>
+    where
+        M::Output: Data,
+        K::Output: Data,
+    {
+        let [batch, m, k, n] = dims;
+        debug_assert_eq!(lhs.len(), batch * m * k);
+        debug_assert_eq!(rhs.len(), batch * k * n);
+
+        let mut output = UnifiedStorage::<K::Output>::alloc(batch * m * n);
+        let lhs_slice = lhs.as_slice();
+        let rhs_slice = rhs.as_slice();
+        let output_slice = output.as_mut_slice();
+
+        // TODO: Cache blocking.
+        // The inner loop walks `rhs` with stride `n`; tile over (i, j, p) for large operands.
+        for b in 0..batch {
+            let lhs_b = &lhs_slice[b * m * k..(b + 1) * m * k];
+            let rhs_b = &rhs_slice[b * k * n..(b + 1) * k * n];
+            let out_b = &mut output_slice[b * m * n..(b + 1) * m * n];
+
+            for i in 0..m {
+                let row = &lhs_b[i * k..(i + 1) * k];
+                for j in 0..n {
+                    let mut acc = reduce.init();
+                    for (p, &l) in row.iter().enumerate() {
+                        acc = reduce.step(acc, mul.apply(l, rhs_b[p * n + j]));
+                    }
+                    out_b[i * n + j] = reduce.finish(acc);
+                }
+            }
+        }
+
+        output
+    }
+
+    fn cdist<F: Data, M: DistanceMetric<F>>(
+        &mut self,
+        lhs: &Self::Storage<F>,
+        rhs: &Self::Storage<F>,
+        dims: [usize; 3],
+        metric: &M,
+    ) -> Self::Storage<F> {
+        let [n_a, n_b, d] = dims;
+        debug_assert_eq!(lhs.len(), n_a * d);
+        debug_assert_eq!(rhs.len(), n_b * d);
+
+        // Per-row work (demeaning, whitening) once per operand row, not once per pair.
+        let prepared = |rows: &[F]| {
+            let mut rows = rows.to_vec();
+            if d > 0 {
+                rows.chunks_exact_mut(d).for_each(|row| metric.prepare(row));
+            }
+            rows
+        };
+        let lhs_rows = prepared(lhs.as_slice());
+        let rhs_rows = prepared(rhs.as_slice());
+
+        let mut output = UnifiedStorage::<F>::alloc(n_a * n_b);
+        let (lhs_slice, rhs_slice) = (lhs_rows.as_slice(), rhs_rows.as_slice());
+        let output_slice = output.as_mut_slice();
+
+        // Tile both row sets so a block of `rhs` rows stays in cache while every row of
+        // the matching `lhs` block is compared against it.
+        const BLOCK: usize = 64;
+        for i0 in (0..n_a).step_by(BLOCK) {
+            for j0 in (0..n_b).step_by(BLOCK) {
+                for i in i0..(i0 + BLOCK).min(n_a) {
+                    let a = &lhs_slice[i * d..(i + 1) * d];
+                    for j in j0..(j0 + BLOCK).min(n_b) {
+                        let b = &rhs_slice[j * d..(j + 1) * d];
+                        output_slice[i * n_b + j] = metric.distance(a, b);
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    fn random<G: CounterRng, D: Distribution>(
+        &mut self,
+        rng: &G,
+        dist: &D,
+        offset: u64,
+        n: usize,
+    ) -> Self::Storage<D::Output> {
+        let mut output = UnifiedStorage::<D::Output>::alloc(n);
+        // TODO: Parallelism.
+        // Chunks are independent; any split gives the same bits.
+        for (i, out) in output.as_mut_slice().iter_mut().enumerate() {
+            *out = dist.sample(rng, offset + i as u64);
+        }
+        output
+    }
+
+    fn quasi_random<F: Real, S: LowDiscrepancy>(
+        &mut self,
+        seq: &S,
+        offset: u64,
+        paths: usize,
+    ) -> Self::Storage<F> {
+        let dims = seq.dims();
+        let mut output = UnifiedStorage::<F>::alloc(paths * dims);
+        for (p, row) in output.as_mut_slice().chunks_exact_mut(dims).enumerate() {
+            for (d, out) in row.iter_mut().enumerate() {
+                *out = F::from_f64(seq.coordinate(offset + p as u64, d));
+            }
+        }
+        output
+    }
+
+    fn brownian_bridge<F: Real>(
+        &mut self,
+        normals: &Self::Storage<F>,
+        bridge: &BrownianBridge<F>,
+    ) -> Self::Storage<F> {
+        let steps = bridge.steps();
+        debug_assert_eq!(normals.len() % steps, 0);
+
+        let mut output = UnifiedStorage::<F>::alloc(normals.len());
+        let rows = normals.as_slice().chunks_exact(steps);
+        for (z, out) in rows.zip(output.as_mut_slice().chunks_exact_mut(steps)) {
+            bridge.increments(z, out);
+        }
+        output
+    }
+}
+
+fn fold_ordered<I: Data, K: ReduceKernel<I>>(kernel: &K, input: &[I]) -> K::Acc {
+    match kernel.order() {
+        ReduceOrder::Sequential => fold(kernel, input),
+        // TODO: Parallelism.
+        // The two halves of every tree node are independent and could run on separate threads.
+        ReduceOrder::Pairwise { block } => fold_pairwise(kernel, input, block),
+        ReduceOrder::Lanes => fold_lanes(kernel, input),
+    }
+}
+
+fn fold_lanes<I: Data, K: ReduceKernel<I>>(kernel: &K, input: &[I]) -> K::Acc {
+    let chunks = input.chunks_exact(LANES);
+    let tail = chunks.remainder();
+    let mut partials = core::array::from_fn(|_| kernel.init());
+    for chunk in chunks {
+        partials = kernel.step_lanes(partials, lanes(chunk));
+    }
+    let acc = partials
+        .into_iter()
+        .reduce(|acc, lane| kernel.merge(acc, lane))
+        .unwrap();
+    tail.iter().fold(acc, |acc, &x| kernel.step(acc, x))
+}
+
+#[inline(always)]
+fn lanes<T: Copy>(chunk: &[T]) -> Lanes<T> {
+    chunk.try_into().unwrap()
+}
+
+fn fold<I: Data, K: ReduceKernel<I>>(kernel: &K, input: &[I]) -> K::Acc {
+    let mut acc = kernel.init();
+    for &val in input {
+        acc = kernel.step(acc, val);
+    }
+    acc
+}
+
+fn fold_pairwise<I: Data, K: ReduceKernel<I>>(kernel: &K, input: &[I], block: usize) -> K::Acc {
+    if input.len() <= block {
+        return fold(kernel, input);
+    }
+    let (left, right) = input.split_at(input.len() / 2);
+    let l_acc = fold_pairwise(kernel, left, block);
+    let r_acc = fold_pairwise(kernel, right, block);
+    kernel.merge(l_acc, r_acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::{
+        ArgMaxKernel, CompensatedSumKernel, Lanewise, LtKernel, MaxKernel, MinKernel, MulKernel,
+        Normal, Pairwise, Philox, ScaleKernel, SumKernel, TradingFloat, VarianceKernel,
+    };
+
+    fn sum_with<K: ReduceKernel<TradingFloat, Output = TradingFloat>>(
+        data: &[TradingFloat],
+        kernel: K,
+    ) -> f64 {
+        let mut backend = GenericBackend::new();
+        let input = backend.pure(data);
+        let out = backend.reduce(&input, kernel);
+        backend.to_host(&out)[0].to_f64()
+    }
+
+    #[test]
+    fn test_summation_policies() {
+        // 0.1 is not representable, every naive step rounds.
+        let n = 1_000_000;
+        let data = vec![TradingFloat::new(0.1); n];
+        let exact = 100_000.0;
+
+        let naive = (sum_with(&data, SumKernel) - exact).abs();
+        let pairwise = (sum_with(&data, Pairwise::new(SumKernel)) - exact).abs();
+        let compensated = (sum_with(&data, CompensatedSumKernel) - exact).abs();
+        let both = (sum_with(&data, Pairwise::new(CompensatedSumKernel)) - exact).abs();
+
+        assert!(naive > 1e-7);
+        assert!(pairwise < naive);
+        assert!(compensated < 1e-9);
+        assert!(both < 1e-9);
+        assert!((data.iter().copied().sum::<TradingFloat>().to_f64() - exact).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_statistical_reductions() {
+        let mut backend = GenericBackend::new();
+        let data: Vec<_> = (0..1000)
+            .map(|i| TradingFloat::new(1e8 + (i % 10) as f64))
+            .collect();
+        let input = backend.pure(&data);
+
+        // Tiny leaves, so almost every partial goes through `merge`.
+        let pairwise = Pairwise {
+            kernel: VarianceKernel::population(),
+            block: 3,
+        };
+        let var = backend.reduce(&input, pairwise);
+        assert!((backend.to_host(&var)[0].to_f64() - 8.25).abs() < 1e-6);
+
+        let argmax = Pairwise {
+            kernel: ArgMaxKernel,
+            block: 3,
+        };
+        let index = backend.reduce(&input, argmax);
+        assert_eq!(backend.to_host(&index), vec![9]);
+    }
+
+    #[test]
+    fn test_lane_dispatch() {
+        let mut backend = GenericBackend::new();
+        // Two full chunks and a tail of five
+        let xs: Vec<f64> = (0..21).map(|i| i as f64 * 0.25 - 2.0).collect();
+        let ys: Vec<f64> = (0..21).map(|i| (i % 4) as f64 - 1.5).collect();
+        let (lhs, rhs) = (backend.pure(&xs), backend.pure(&ys));
+
+        let product = backend.binary(&lhs, &rhs, MulKernel);
+        let expected: Vec<f64> = xs.iter().zip(&ys).map(|(x, y)| x * y).collect();
+        assert_eq!(backend.to_host(&product), expected);
+
+        let mask = backend.binary(&lhs, &rhs, LtKernel);
+        let expected: Vec<bool> = xs.iter().zip(&ys).map(|(x, y)| x < y).collect();
+        assert_eq!(backend.to_host(&mask), expected);
+
+        let scaled = backend.unary(&lhs, ScaleKernel { factor: 4.0 });
+        let expected: Vec<f64> = xs.iter().map(|x| x * 4.0).collect();
+        assert_eq!(backend.to_host(&scaled), expected);
+
+        // Lane partials: exact for integers and order-free kernels.
+        let ints: Vec<i64> = (1..=21).collect();
+        let ints = backend.pure(&ints);
+        let total = backend.reduce(&ints, Lanewise(SumKernel));
+        assert_eq!(backend.to_host(&total), vec![231]);
+        let max = backend.reduce(&lhs, Lanewise(MaxKernel));
+        assert_eq!(backend.to_host(&max), vec![3.0]);
+        let min = backend.reduce(&rhs, Lanewise(MinKernel));
+        assert_eq!(backend.to_host(&min), vec![-1.5]);
+
+        let data = vec![TradingFloat::new(0.1); 10_001];
+        let lanewise = (sum_with(&data, Lanewise(SumKernel)) - 1000.1).abs();
+        assert!(lanewise < (sum_with(&data, SumKernel) - 1000.1).abs());
+    }
+
+    #[test]
+    fn test_random_is_chunk_invariant() {
+        let mut backend = GenericBackend::new();
+        let rng = Philox::new(2024, 1);
+        let normal = Normal::<TradingFloat>::standard();
+
+        // One pass, or two calls that split the index range, give the same bits.
+        let whole = backend.random(&rng, &normal, 0, 101);
+        let head = backend.random(&rng, &normal, 0, 37);
+        let tail = backend.random(&rng, &normal, 37, 64);
+        let mut joined = backend.to_host(&head);
+        joined.extend(backend.to_host(&tail));
+        assert_eq!(backend.to_host(&whole), joined);
+
+        // Another stream of the same seed is a different sequence.
+        let other = backend.random(&Philox::new(2024, 2), &normal, 0, 101);
+        assert_ne!(backend.to_host(&other), joined);
+    }
 }
@@ -1,10 +1,16 @@
-use algebra::{BinaryKernel, Data, ReduceKernel, StreamKernel, UnaryKernel};
+use algebra::{
+    BinaryKernel, BrownianBridge, CounterRng, Data, DifferentiableStreamKernel, DistanceMetric,
+    Distribution, LowDiscrepancy, Real, ReduceKernel, Semiring, StreamKernel, UnaryKernel,
+};
 use std::fmt::Debug;
 
 pub trait Storage: Debug + Clone + Send + Sync {
     type Elem: Data;
 
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn alloc(n: usize) -> Self;
     fn as_slice(&self) -> &[Self::Elem];
     fn as_mut_slice(&mut self) -> &mut [Self::Elem];
@@ -53,21 +59,124 @@ pub trait Backend {
     where
         K::Output: Data;
 
+    // Reduces the middle axis of a dense [outer, len, inner] array, giving [outer, inner].
+    fn reduce_axis<I: Data, K: ReduceKernel<I>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        dims: [usize; 3],
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Data;
+
+    // Runs `kernel` over each dense row of `len` elements, with fresh state per row,
+    // e.g. one simulated path per row of [paths, steps].
     fn stream<I: Data, K: StreamKernel<I>>(
         &mut self,
         input: &Self::Storage<I>,
+        len: usize,
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Data;
+
+    // Pulls the cotangents of a `stream` back to where each row started: one value per
+    // row, the sum over the row of grad[t] * d output[t] / d start.
+    fn stream_pullback<I: Data, K: DifferentiableStreamKernel<I>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        grad: &Self::Storage<K::Output>,
+        len: usize,
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Semiring;
+
+    // out[i] = if mask[i] { then_[i] } else { else_[i] }, without branching on the mask.
+    fn select<T: Data>(
+        &mut self,
+        mask: &Self::Storage<bool>,
+        then_: &Self::Storage<T>,
+        else_: &Self::Storage<T>,
+    ) -> Self::Storage<T>;
+
+    // out[i] = kernel(params[(i / inner) % params.len()], input[i]) over dense input:
+    // runs of `inner` elements share a parameter, e.g. one scale per index of an axis.
+    fn grouped<P: Data, I: Data, K: BinaryKernel<P, I>>(
+        &mut self,
+        params: &Self::Storage<P>,
+        input: &Self::Storage<I>,
+        inner: usize,
+        kernel: K,
+    ) -> Self::Storage<K::Output>
+    where
+        K::Output: Data;
+
+    // Inclusive scan along contiguous rows of `len` elements:
+    // out[r, 0] = kernel(init, in[r, 0]), out[r, t] = kernel(out[r, t - 1], in[r, t]).
+    fn scan<I: Data, A: Data, K: BinaryKernel<A, I, Output = A>>(
+        &mut self,
+        input: &Self::Storage<I>,
+        len: usize,
+        init: A,
         kernel: K,
+    ) -> Self::Storage<A>;
+
+    // Batched GEMM over an arbitrary semiring.
+    // `lhs` is dense [batch, m, k], `rhs` is dense [batch, k, n], the result is [batch, m, n]
+    // with out[b, i, j] = reduce_p mul(lhs[b, i, p], rhs[b, p, j]).
+    // TODO: Tiling / BLAS.
+    // Dense float contractions should hook into BLAS/cuBLAS instead of the generic loop.
+    fn contract<L: Data, R: Data, M: BinaryKernel<L, R>, K: ReduceKernel<M::Output>>(
+        &mut self,
+        lhs: &Self::Storage<L>,
+        rhs: &Self::Storage<R>,
+        dims: [usize; 4],
+        mul: M,
+        reduce: K,
     ) -> Self::Storage<K::Output>
     where
+        M::Output: Data,
         K::Output: Data;
 
-    // TODO: GEMM / Linear Algebra.
-    // `reduce` is generic, but Matrix Multiplication requires specialized tiling/cache algorithms.
-    // A `matmul` or `gemm` function should be part of the Backend trait to hook into BLAS/cuBLAS.
+    // Pairwise distances between the rows of two matrices.
+    // `lhs` is dense [n_a, d], `rhs` is dense [n_b, d], the result is [n_a, n_b]
+    // with out[i, j] = metric(lhs[i, :], rhs[j, :]). Implementations `prepare` each row
+    // once before comparing pairs.
+    fn cdist<F: Data, M: DistanceMetric<F>>(
+        &mut self,
+        lhs: &Self::Storage<F>,
+        rhs: &Self::Storage<F>,
+        dims: [usize; 3],
+        metric: &M,
+    ) -> Self::Storage<F>;
+
+    // `n` samples of `dist` at indices `offset..offset + n` of `rng`.
+    // Each sample is a pure function of its index, so the result must not depend on how
+    // an implementation splits the range across chunks, threads or devices.
+    fn random<G: CounterRng, D: Distribution>(
+        &mut self,
+        rng: &G,
+        dist: &D,
+        offset: u64,
+        n: usize,
+    ) -> Self::Storage<D::Output>;
 
-    // TODO: Random Number Generation.
-    // fn random_uniform(&mut self, shape: &[usize], min: f32, max: f32) -> Self::Storage<f32>;
-    // fn random_normal(&mut self, shape: &[usize], mean: f32, std: f32) -> Self::Storage<f32>;
+    // Points `offset..offset + paths` of `seq` as dense [paths, seq.dims()].
+    fn quasi_random<F: Real, S: LowDiscrepancy>(
+        &mut self,
+        seq: &S,
+        offset: u64,
+        paths: usize,
+    ) -> Self::Storage<F>;
+
+    // `normals` is dense [paths, bridge.steps()] in bridge order; each row becomes the
+    // Brownian increments of one path, in time order.
+    fn brownian_bridge<F: Real>(
+        &mut self,
+        normals: &Self::Storage<F>,
+        bridge: &BrownianBridge<F>,
+    ) -> Self::Storage<F>;
 }
 
 // TODO: Checkpoints
@@ -78,4 +187,3 @@ pub trait Backend {
 //     fn delete(&mut self, id: CheckpointId);
 // }
 // TODO: Distributed computation and shared storage
-// TODO: Quantization
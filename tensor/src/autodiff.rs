@@ -1,5 +1,8 @@
-use super::{Base, Pullback};
-use algebra::{Data, Real};
+use super::eval::{from_lanes, lanes};
+use super::{Base, Differentiable, Evaluator, Lower, PackDense, Pullback};
+use algebra::{
+    Data, DifferentiableKernel, DifferentiableStreamKernel, MapExpr, Real, StreamExpr, VjpKernel,
+};
 use backend::Backend;
 use core::marker::PhantomData;
 
@@ -15,6 +18,12 @@ impl<F: Real, const R: usize> LeafAdjoint<F, R> {
     }
 }
 
+impl<F: Real, const R: usize> Default for LeafAdjoint<F, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<F: Real, B: Backend, const R: usize> Pullback<B, R> for LeafAdjoint<F, R> {
     type Primal = F;
     type Cotangent = F;
@@ -37,13 +46,133 @@ impl<T: Data, const R: usize> NoGrad<T, R> {
     }
 }
 
+impl<T: Data, const R: usize> Default for NoGrad<T, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<B: Backend, T: Data, const R: usize> Pullback<B, R> for NoGrad<T, R> {
     type Primal = T;
     type Cotangent = ();
     type Gradients = ();
 
-    fn back(&self, _backend: &mut B, _grad: Base<B::Storage<()>, (), R>) {
-        ()
+    fn back(&self, _backend: &mut B, _grad: Base<B::Storage<()>, (), R>) {}
+}
+
+// Element-wise map: keeps the dense input for the local derivative.
+pub struct MapAdjoint<B: Backend, A, K, F: Data, const R: usize> {
+    inner: A,
+    kernel: K,
+    input: Base<B::Storage<F>, F, R>,
+}
+
+impl<B, A, K, F, const R: usize> Pullback<B, R> for MapAdjoint<B, A, K, F, R>
+where
+    B: Backend,
+    F: Real,
+    A: Pullback<B, R, Primal = F, Cotangent = F>,
+    K: DifferentiableKernel<F>,
+{
+    type Primal = F;
+    type Cotangent = F;
+    type Gradients = A::Gradients;
+
+    fn back(&self, backend: &mut B, grad: Base<B::Storage<F>, F, R>) -> Self::Gradients {
+        let grad = Lower::<PackDense, B>::lower(&grad, backend);
+        let kernel = VjpKernel {
+            kernel: self.kernel,
+        };
+        let storage = backend.binary(&grad, &self.input.storage, kernel);
+        self.inner
+            .back(backend, Base::new(storage, self.input.shape))
+    }
+}
+
+impl<B, Op, K, const R: usize> Differentiable<B, R> for MapExpr<Op, K>
+where
+    B: Backend,
+    Op: Differentiable<B, R>,
+    Op::Data: Real,
+    K: DifferentiableKernel<Op::Data>,
+{
+    type Adjoint = MapAdjoint<B, Op::Adjoint, K, Op::Data, R>;
+
+    fn forward(&self, backend: &mut B) -> (Base<B::Storage<Op::Data>, Op::Data, R>, Self::Adjoint) {
+        let (view, inner) = self.op.forward(backend);
+        let input = Lower::<PackDense, B>::lower(&view, backend);
+        let storage = backend.unary(&input, self.kernel);
+
+        let adjoint = MapAdjoint {
+            inner,
+            kernel: self.kernel,
+            input: Base::new(input, view.shape),
+        };
+        (Base::new(storage, view.shape), adjoint)
+    }
+}
+
+// Stream along an axis: the gradient goes to where each lane started, e.g. the spot of
+// each simulated path, as one value per lane with the axis shrunk to 1. The inputs (shocks)
+// get none, so they need not be differentiable.
+pub struct StreamAdjoint<B: Backend, K, I: Data, const R: usize> {
+    kernel: K,
+    input: B::Storage<I>,
+    shape: [usize; R],
+    axis: usize,
+}
+
+impl<B, K, I, const R: usize> Pullback<B, R> for StreamAdjoint<B, K, I, R>
+where
+    B: Backend,
+    I: Data,
+    K: DifferentiableStreamKernel<I>,
+    K::Output: Real,
+{
+    type Primal = K::Output;
+    type Cotangent = K::Output;
+    type Gradients = Base<B::Storage<K::Output>, K::Output, R>;
+
+    fn back(
+        &self,
+        backend: &mut B,
+        grad: Base<B::Storage<K::Output>, K::Output, R>,
+    ) -> Self::Gradients {
+        let grad = lanes(backend, grad, self.axis);
+        let len = self.shape[self.axis];
+        let storage = backend.stream_pullback(&self.input, &grad, len, self.kernel);
+
+        let mut shape = self.shape;
+        shape[self.axis] = 1;
+        from_lanes(storage, shape, self.axis)
+    }
+}
+
+impl<B, Op, K, const R: usize> Differentiable<B, R> for StreamExpr<Op, K>
+where
+    B: Backend,
+    Op: Evaluator<B, R>,
+    K: DifferentiableStreamKernel<Op::Data>,
+    K::Output: Real,
+{
+    type Adjoint = StreamAdjoint<B, K, Op::Data, R>;
+
+    fn forward(
+        &self,
+        backend: &mut B,
+    ) -> (Base<B::Storage<K::Output>, K::Output, R>, Self::Adjoint) {
+        let view = self.op.eval(backend);
+        let shape = view.shape;
+        let input = lanes(backend, view, self.axis);
+        let storage = backend.stream(&input, shape[self.axis], self.kernel);
+
+        let adjoint = StreamAdjoint {
+            kernel: self.kernel,
+            input,
+            shape,
+            axis: self.axis,
+        };
+        (from_lanes(storage, shape, self.axis), adjoint)
     }
 }
 
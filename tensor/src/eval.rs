@@ -1,6 +1,9 @@
 use super::{Base, Evaluator, Lower, PackDense};
 use algebra::{
-    BinaryKernel, Data, MapExpr, ReshapeExpr, SliceExpr, TransposeExpr, UnaryKernel, ZipExpr,
+    BinaryKernel, BridgeExpr, BroadcastExpr, CDistExpr, ChainedKernel, ConstExpr, ContractExpr,
+    CounterRng, Data, DistanceMetric, Distribution, GroupedExpr, IfExpr, LowDiscrepancy, MapExpr,
+    QuasiRandomExpr, RandExpr, Real, ReduceExpr, ReduceKernel, ReshapeExpr, ScanExpr, SliceExpr,
+    StreamExpr, StreamKernel, SumKernel, TransposeExpr, UnaryKernel, ZipExpr,
 };
 use backend::Backend;
 
@@ -15,18 +18,175 @@ where
     type Data = K::Output;
 
     fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, RANK> {
-        let l_view = self.left.eval(backend);
-        let r_view = self.right.eval(backend);
+        zip_with(backend, &self.left, &self.right, self.kernel)
+    }
+
+    // Unary after binary: maps on top of a zip run inside the same binary pass.
+    fn eval_map<K2: UnaryKernel<Self::Data>>(
+        &self,
+        backend: &mut B,
+        kernel: K2,
+    ) -> Base<B::Storage<K2::Output>, K2::Output, RANK> {
+        let chained = ChainedKernel {
+            k1: self.kernel,
+            k2: kernel,
+        };
+        zip_with(backend, &self.left, &self.right, chained)
+    }
+}
+
+fn zip_with<B, L, R, K, const RANK: usize>(
+    backend: &mut B,
+    left: &L,
+    right: &R,
+    kernel: K,
+) -> Base<B::Storage<K::Output>, K::Output, RANK>
+where
+    B: Backend,
+    L: Evaluator<B, RANK>,
+    R: Evaluator<B, RANK>,
+    K: BinaryKernel<L::Data, R::Data>,
+{
+    let l_view = left.eval(backend);
+    let r_view = right.eval(backend);
+    let shape = broadcast_shape([l_view.shape, r_view.shape]);
+
+    let l_dense = Lower::<PackDense, B>::lower(&broadcast_to(l_view, shape), backend);
+    let r_dense = Lower::<PackDense, B>::lower(&broadcast_to(r_view, shape), backend);
+
+    let storage = backend.binary(&l_dense, &r_dense, kernel);
+
+    Base::new(storage, shape)
+}
 
-        let l_dense = Lower::<PackDense, B>::lower(&l_view, backend);
-        let r_dense = Lower::<PackDense, B>::lower(&r_view, backend);
+// Element-wise selection. Both branches are evaluated; the backend picks per element.
+impl<B, C, T, El, const RANK: usize> Evaluator<B, RANK> for IfExpr<C, T, El>
+where
+    B: Backend,
+    C: Evaluator<B, RANK, Data = bool>,
+    T: Evaluator<B, RANK>,
+    El: Evaluator<B, RANK, Data = T::Data>,
+{
+    type Data = T::Data;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, RANK> {
+        let c_view = self.cond.eval(backend);
+        let t_view = self.then_.eval(backend);
+        let e_view = self.else_.eval(backend);
+        let shape = broadcast_shape([c_view.shape, t_view.shape, e_view.shape]);
 
-        let storage = backend.binary(&l_dense, &r_dense, self.kernel);
+        let mask = Lower::<PackDense, B>::lower(&broadcast_to(c_view, shape), backend);
+        let then_ = Lower::<PackDense, B>::lower(&broadcast_to(t_view, shape), backend);
+        let else_ = Lower::<PackDense, B>::lower(&broadcast_to(e_view, shape), backend);
 
-        Base::new(storage, l_view.shape)
+        let storage = backend.select(&mask, &then_, &else_);
+
+        Base::new(storage, shape)
     }
 }
 
+// A constant is a single element broadcast along every axis.
+impl<B, F, const RANK: usize> Evaluator<B, RANK> for ConstExpr<F>
+where
+    B: Backend,
+    F: Data,
+{
+    type Data = F;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<F>, F, RANK> {
+        let storage = backend.pure(&[self.0]);
+        Base::from_parts(storage, [1; RANK], [0; RANK], 0)
+    }
+}
+
+// New axes and size-1 source axes get stride 0; nothing is copied until the view is lowered.
+impl<B, Op, const R_IN: usize, const R_OUT: usize> Evaluator<B, R_OUT>
+    for BroadcastExpr<Op, R_IN, R_OUT>
+where
+    B: Backend,
+    Op: Evaluator<B, R_IN>,
+{
+    type Data = Op::Data;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, R_OUT> {
+        let view = self.op.eval(backend);
+
+        let mut strides = [0; R_OUT];
+        for (axis, source) in self.mapping.iter().enumerate() {
+            if let Some(src) = *source {
+                let (from, to) = (view.shape[src], self.target_shape[axis]);
+                assert!(
+                    from == to || from == 1,
+                    "broadcast: axis {axis} of size {from} cannot expand to {to}"
+                );
+                if from == to {
+                    strides[axis] = view.strides[src];
+                }
+            }
+        }
+
+        Base::from_parts(view.storage, self.target_shape, strides, view.offset)
+    }
+}
+
+impl<B, G, D, const RANK: usize> Evaluator<B, RANK> for RandExpr<G, D, RANK>
+where
+    B: Backend,
+    G: CounterRng,
+    D: Distribution,
+{
+    type Data = D::Output;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<D::Output>, D::Output, RANK> {
+        let n = self.shape.iter().product();
+        let storage = backend.random(&self.rng, &self.dist, self.offset, n);
+        Base::new(storage, self.shape)
+    }
+}
+
+impl<B, S, F> Evaluator<B, 2> for QuasiRandomExpr<S, F>
+where
+    B: Backend,
+    S: LowDiscrepancy,
+    F: Real,
+{
+    type Data = F;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<F>, F, 2> {
+        let storage = backend.quasi_random(&self.seq, self.offset, self.paths);
+        Base::new(storage, [self.paths, self.seq.dims()])
+    }
+}
+
+// Axis-wise maximum; `broadcast_to` rejects anything but equal or size-1 axes.
+fn broadcast_shape<const R: usize, const N: usize>(shapes: [[usize; R]; N]) -> [usize; R] {
+    let mut shape = [1; R];
+    for s in &shapes {
+        for (dim, &d) in shape.iter_mut().zip(s) {
+            *dim = (*dim).max(d);
+        }
+    }
+    shape
+}
+
+// Stretches size-1 axes of `view` to `shape` with zero strides.
+fn broadcast_to<S, F, const R: usize>(view: Base<S, F, R>, shape: [usize; R]) -> Base<S, F, R> {
+    let mut strides = view.strides;
+    for axis in 0..R {
+        if view.shape[axis] != shape[axis] {
+            assert_eq!(
+                view.shape[axis], 1,
+                "broadcast: axis {axis} of size {} does not match {}",
+                view.shape[axis], shape[axis]
+            );
+            strides[axis] = 0;
+        }
+    }
+    Base::from_parts(view.storage, shape, strides, view.offset)
+}
+
+// Nested maps collapse into one `ChainedKernel` that the innermost expression applies,
+// so `x.map(f).map(g).map(h)` reads and writes memory once.
 impl<B, Op, K, const RANK: usize> Evaluator<B, RANK> for MapExpr<Op, K>
 where
     B: Backend,
@@ -36,13 +196,111 @@ where
 {
     type Data = K::Output;
 
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, RANK> {
+        self.op.eval_map(backend, self.kernel)
+    }
+
+    fn eval_map<K2: UnaryKernel<Self::Data>>(
+        &self,
+        backend: &mut B,
+        kernel: K2,
+    ) -> Base<B::Storage<K2::Output>, K2::Output, RANK> {
+        let chained = ChainedKernel {
+            k1: self.kernel,
+            k2: kernel,
+        };
+        self.op.eval_map(backend, chained)
+    }
+}
+
+// Scans run along the last axis.
+impl<B, Op, A, K, const RANK: usize> Evaluator<B, RANK> for ScanExpr<Op, A, K>
+where
+    B: Backend,
+    Op: Evaluator<B, RANK>,
+    A: Data,
+    K: BinaryKernel<A, Op::Data, Output = A>,
+{
+    type Data = A;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<A>, A, RANK> {
+        let view = self.op.eval(backend);
+        let input = Lower::<PackDense, B>::lower(&view, backend);
+        let len = view.shape.last().copied().unwrap_or(1);
+
+        let storage = backend.scan(&input, len, self.init, self.kernel);
+
+        Base::new(storage, view.shape)
+    }
+}
+
+impl<B, Op, K, const R_IN: usize, const R_OUT: usize> Evaluator<B, R_OUT>
+    for ReduceExpr<Op, K, R_IN>
+where
+    B: Backend,
+    Op: Evaluator<B, R_IN>,
+    K: ReduceKernel<Op::Data>,
+{
+    type Data = K::Output;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, R_OUT> {
+        debug_assert_eq!(R_OUT + 1, R_IN, "reduce: drops exactly one axis");
+        let view = self.op.eval(backend);
+        let input = Lower::<PackDense, B>::lower(&view, backend);
+
+        let dims = [
+            view.shape[..self.axis].iter().product(),
+            view.shape[self.axis],
+            view.shape[self.axis + 1..].iter().product(),
+        ];
+        let storage = backend.reduce_axis(&input, dims, self.kernel);
+
+        let mut shape = [0; R_OUT];
+        let kept = view
+            .shape
+            .iter()
+            .enumerate()
+            .filter(|&(a, _)| a != self.axis);
+        for (dst, (_, &dim)) in shape.iter_mut().zip(kept) {
+            *dst = dim;
+        }
+        Base::new(storage, shape)
+    }
+}
+
+impl<B, Op, P, K, const RANK: usize> Evaluator<B, RANK> for GroupedExpr<Op, P, K>
+where
+    B: Backend,
+    Op: Evaluator<B, RANK>,
+    P: Data,
+    K: BinaryKernel<P, Op::Data>,
+{
+    type Data = K::Output;
+
     fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, RANK> {
         let view = self.op.eval(backend);
         let input = Lower::<PackDense, B>::lower(&view, backend);
 
-        let storage = backend.unary(&input, self.kernel);
+        // Dense row-major: an index along `axis` covers a run of `inner` elements.
+        let inner = match self.axis {
+            Some(axis) => {
+                assert_eq!(
+                    self.params.len(),
+                    view.shape[axis],
+                    "grouped: one parameter per index of axis {axis}"
+                );
+                view.shape[axis + 1..].iter().product()
+            }
+            None => {
+                assert_eq!(self.params.len(), 1, "grouped: expected a single parameter");
+                view.numel()
+            }
+        };
+        let params = backend.pure(&self.params);
 
-        Base::from_parts(storage, view.shape, view.strides, view.offset)
+        let storage = backend.grouped(&params, &input, inner.max(1), self.kernel);
+
+        Base::new(storage, view.shape)
     }
 }
 
@@ -109,3 +367,215 @@ where
         base
     }
 }
+
+// Reorders the axes of a view without touching its storage.
+fn permuted<S, F, const R: usize>(view: Base<S, F, R>, order: &[usize]) -> Base<S, F, R> {
+    debug_assert_eq!(order.len(), R, "every axis must appear exactly once");
+    let mut shape = [0; R];
+    let mut strides = [0; R];
+    for (i, &src) in order.iter().enumerate() {
+        shape[i] = view.shape[src];
+        strides[i] = view.strides[src];
+    }
+    Base::from_parts(view.storage, shape, strides, view.offset)
+}
+
+impl<B, L, R, M, const K: usize, const R_L: usize, const R_R: usize, const R_OUT: usize>
+    Evaluator<B, R_OUT> for ContractExpr<L, R, M, K, R_L, R_R, R_OUT>
+where
+    B: Backend,
+    L: Evaluator<B, R_L>,
+    R: Evaluator<B, R_R>,
+    M: BinaryKernel<L::Data, R::Data>,
+    SumKernel: ReduceKernel<M::Output>,
+    <SumKernel as ReduceKernel<M::Output>>::Output: Data,
+{
+    type Data = <SumKernel as ReduceKernel<M::Output>>::Output;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, R_OUT> {
+        let l_view = self.left.eval(backend);
+        let r_view = self.right.eval(backend);
+
+        // GEMM roles of the output axes
+        let (mut batch, mut l_free, mut r_free) = (Vec::new(), Vec::new(), Vec::new());
+        for (axis, sources) in self.left_map.iter().zip(&self.right_map).enumerate() {
+            match sources {
+                (Some(_), Some(_)) => batch.push(axis),
+                (Some(_), None) => l_free.push(axis),
+                (None, Some(_)) => r_free.push(axis),
+                (None, None) => unreachable!("output axis {axis} has no source"),
+            }
+        }
+
+        let left_of = |axis: &usize| self.left_map[*axis].unwrap();
+        let right_of = |axis: &usize| self.right_map[*axis].unwrap();
+
+        for axis in &batch {
+            assert_eq!(
+                l_view.shape[left_of(axis)],
+                r_view.shape[right_of(axis)],
+                "contract: batch axis size mismatch"
+            );
+        }
+        for (&l, &r) in self.left_axes.iter().zip(&self.right_axes) {
+            assert_eq!(
+                l_view.shape[l], r_view.shape[r],
+                "contract: contracted axis size mismatch"
+            );
+        }
+
+        let mut shape = [0; R_OUT];
+        for (axis, dim) in shape.iter_mut().enumerate() {
+            *dim = match self.left_map[axis] {
+                Some(l) => l_view.shape[l],
+                None => r_view.shape[right_of(&axis)],
+            };
+        }
+
+        let size = |axes: &[usize]| axes.iter().map(|&a| shape[a]).product();
+        let dims = [
+            size(&batch),
+            size(&l_free),
+            self.left_axes.iter().map(|&a| l_view.shape[a]).product(),
+            size(&r_free),
+        ];
+
+        // lhs -> [batch, l_free, contracted], rhs -> [batch, contracted, r_free]
+        let l_order: Vec<usize> = batch
+            .iter()
+            .chain(&l_free)
+            .map(left_of)
+            .chain(self.left_axes)
+            .collect();
+        let r_order: Vec<usize> = batch
+            .iter()
+            .map(right_of)
+            .chain(self.right_axes)
+            .chain(r_free.iter().map(right_of))
+            .collect();
+
+        let lhs = Lower::<PackDense, B>::lower(&permuted(l_view, &l_order), backend);
+        let rhs = Lower::<PackDense, B>::lower(&permuted(r_view, &r_order), backend);
+        let storage = backend.contract(&lhs, &rhs, dims, self.mul, SumKernel);
+
+        // The result is dense in [batch, l_free, r_free] order; view it in output order.
+        let mut strides = [0; R_OUT];
+        let mut stride = 1;
+        for &axis in batch.iter().chain(&l_free).chain(&r_free).rev() {
+            strides[axis] = stride;
+            stride *= shape[axis];
+        }
+
+        Base::from_parts(storage, shape, strides, 0)
+    }
+}
+
+impl<B, L, R, M> Evaluator<B, 2> for CDistExpr<L, R, M>
+where
+    B: Backend,
+    L: Evaluator<B, 2>,
+    R: Evaluator<B, 2, Data = L::Data>,
+    M: DistanceMetric<L::Data>,
+{
+    type Data = L::Data;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, 2> {
+        let l_view = permuted(self.left.eval(backend), &self.left_axes);
+        let r_view = permuted(self.right.eval(backend), &self.right_axes);
+        let ([n_a, d], [n_b, d_r]) = (l_view.shape, r_view.shape);
+        assert_eq!(d, d_r, "cdist: feature axis size mismatch");
+
+        // Both operands as dense [rows, feature]
+        let lhs = Lower::<PackDense, B>::lower(&l_view, backend);
+        let rhs = Lower::<PackDense, B>::lower(&r_view, backend);
+        let storage = backend.cdist(&lhs, &rhs, [n_a, n_b, d], &self.metric);
+
+        Base::new(storage, [n_a, n_b])
+    }
+}
+
+impl<B, Op, F, const RANK: usize> Evaluator<B, RANK> for BridgeExpr<Op, F>
+where
+    B: Backend,
+    Op: Evaluator<B, RANK, Data = F>,
+    F: Real,
+{
+    type Data = F;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<F>, F, RANK> {
+        let view = self.op.eval(backend);
+        assert_eq!(
+            view.shape[self.axis],
+            self.bridge.steps(),
+            "brownian bridge: time axis size mismatch"
+        );
+        along_axis(backend, view, self.axis, |backend, normals| {
+            backend.brownian_bridge(normals, &self.bridge)
+        })
+    }
+}
+
+impl<B, Op, K, const RANK: usize> Evaluator<B, RANK> for StreamExpr<Op, K>
+where
+    B: Backend,
+    Op: Evaluator<B, RANK>,
+    K: StreamKernel<Op::Data>,
+{
+    type Data = K::Output;
+
+    fn eval(&self, backend: &mut B) -> Base<B::Storage<K::Output>, K::Output, RANK> {
+        let view = self.op.eval(backend);
+        let len = view.shape[self.axis];
+        along_axis(backend, view, self.axis, |backend, input| {
+            backend.stream(input, len, self.kernel)
+        })
+    }
+}
+
+// Moves `axis` last so every lane along it is one dense row, lets `f` transform the rows,
+// and views the result in the original axis order.
+fn along_axis<B, I, O, const R: usize>(
+    backend: &mut B,
+    view: Base<B::Storage<I>, I, R>,
+    axis: usize,
+    f: impl FnOnce(&mut B, &B::Storage<I>) -> B::Storage<O>,
+) -> Base<B::Storage<O>, O, R>
+where
+    B: Backend,
+    I: Data,
+    O: Data,
+{
+    let shape = view.shape;
+    let rows = lanes(backend, view, axis);
+    let storage = f(backend, &rows);
+    from_lanes(storage, shape, axis)
+}
+
+// The lanes along `axis` as dense rows, in the order of the remaining axes.
+pub(crate) fn lanes<B, T, const R: usize>(
+    backend: &mut B,
+    view: Base<B::Storage<T>, T, R>,
+    axis: usize,
+) -> B::Storage<T>
+where
+    B: Backend,
+    T: Data,
+{
+    let order: Vec<usize> = (0..R).filter(|&a| a != axis).chain([axis]).collect();
+    Lower::<PackDense, B>::lower(&permuted(view, &order), backend)
+}
+
+// Views dense rows laid out by `lanes` with the given `shape`, in the original axis order.
+pub(crate) fn from_lanes<S, T, const R: usize>(
+    storage: S,
+    shape: [usize; R],
+    axis: usize,
+) -> Base<S, T, R> {
+    let mut strides = [0; R];
+    let mut stride = 1;
+    for a in (0..R).filter(|&a| a != axis).chain([axis]).rev() {
+        strides[a] = stride;
+        stride *= shape[a];
+    }
+    Base::from_parts(storage, shape, strides, 0)
+}
@@ -9,6 +9,8 @@ pub mod lift;
 pub use lift::*;
 pub mod autodiff;
 pub mod ops;
+pub mod quant;
 pub use autodiff::*;
+pub use quant::*;
 pub mod lower;
 pub use lower::*;
@@ -12,8 +12,9 @@ pub struct PackDense;
 // TODO: Kernel Compilation Pass.
 // The `Lower` trait is effectively a compiler pass.
 // Currently, it handles memory compaction.
-// It should eventually handle "Kernel Fusion" -- taking a graph of Lazy expressions
-// and lowering them into a single compiled Backend::Kernel.
+// Chains of maps (optionally ending in one zip) already fuse through `Evaluator::eval_map`.
+// Zips of zips, e.g. `(a + b) * c`, still materialize every level; lowering the whole
+// element-wise graph into a single compiled Backend::Kernel would cover them too.
 pub trait Lower<Target, B> {
     type Output;
 
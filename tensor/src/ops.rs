@@ -1,6 +1,9 @@
 use super::{Lift, Tensor};
-use algebra::{AddKernel, BroadcastMap, BroadcastShape, Semiring, Shape, ZipExpr};
-use std::ops::Add;
+use algebra::{
+    AddKernel, AndKernel, BroadcastMap, BroadcastShape, DivKernel, Field, MapExpr, MulKernel,
+    NotKernel, OrKernel, Ring, Semiring, Shape, SubKernel, ZipExpr,
+};
+use std::ops::{Add, BitAnd, BitOr, Div, Mul, Not, Sub};
 
 // TODO: Auto broadcast
 // fn infer_union_shape<const RL: usize, const RR: usize, const ROUT: usize>(
@@ -61,3 +64,110 @@ where
         })
     }
 }
+
+impl<F, ShL, ShR, EL, ER> Sub<Tensor<F, ShR, ER>> for Tensor<F, ShL, EL>
+where
+    F: Ring,
+    ShL: Shape,
+    ShR: Shape,
+    ShL: BroadcastShape<ShR>,
+    <ShL as BroadcastShape<ShR>>::Output: BroadcastMap<ShL> + BroadcastMap<ShR>,
+    [(); <ShL as BroadcastShape<ShR>>::Output::RANK]:,
+{
+    type Output = Tensor<F, <ShL as BroadcastShape<ShR>>::Output, ZipExpr<EL, ER, SubKernel>>;
+
+    fn sub(self, rhs: Tensor<F, ShR, ER>) -> Self::Output {
+        Tensor::wrap(ZipExpr {
+            left: self.expr,
+            right: rhs.expr,
+            kernel: SubKernel,
+        })
+    }
+}
+
+impl<F, ShL, ShR, EL, ER> Mul<Tensor<F, ShR, ER>> for Tensor<F, ShL, EL>
+where
+    F: Semiring,
+    ShL: Shape,
+    ShR: Shape,
+    ShL: BroadcastShape<ShR>,
+    <ShL as BroadcastShape<ShR>>::Output: BroadcastMap<ShL> + BroadcastMap<ShR>,
+    [(); <ShL as BroadcastShape<ShR>>::Output::RANK]:,
+{
+    type Output = Tensor<F, <ShL as BroadcastShape<ShR>>::Output, ZipExpr<EL, ER, MulKernel>>;
+
+    fn mul(self, rhs: Tensor<F, ShR, ER>) -> Self::Output {
+        Tensor::wrap(ZipExpr {
+            left: self.expr,
+            right: rhs.expr,
+            kernel: MulKernel,
+        })
+    }
+}
+
+impl<F, ShL, ShR, EL, ER> Div<Tensor<F, ShR, ER>> for Tensor<F, ShL, EL>
+where
+    F: Field,
+    ShL: Shape,
+    ShR: Shape,
+    ShL: BroadcastShape<ShR>,
+    <ShL as BroadcastShape<ShR>>::Output: BroadcastMap<ShL> + BroadcastMap<ShR>,
+    [(); <ShL as BroadcastShape<ShR>>::Output::RANK]:,
+{
+    type Output = Tensor<F, <ShL as BroadcastShape<ShR>>::Output, ZipExpr<EL, ER, DivKernel>>;
+
+    fn div(self, rhs: Tensor<F, ShR, ER>) -> Self::Output {
+        Tensor::wrap(ZipExpr {
+            left: self.expr,
+            right: rhs.expr,
+            kernel: DivKernel,
+        })
+    }
+}
+
+// Logical ops on `bool` masks
+impl<ShL, ShR, EL, ER> BitAnd<Tensor<bool, ShR, ER>> for Tensor<bool, ShL, EL>
+where
+    ShL: Shape,
+    ShR: Shape,
+    ShL: BroadcastShape<ShR>,
+    <ShL as BroadcastShape<ShR>>::Output: BroadcastMap<ShL> + BroadcastMap<ShR>,
+    [(); <ShL as BroadcastShape<ShR>>::Output::RANK]:,
+{
+    type Output = Tensor<bool, <ShL as BroadcastShape<ShR>>::Output, ZipExpr<EL, ER, AndKernel>>;
+
+    fn bitand(self, rhs: Tensor<bool, ShR, ER>) -> Self::Output {
+        Tensor::wrap(ZipExpr {
+            left: self.expr,
+            right: rhs.expr,
+            kernel: AndKernel,
+        })
+    }
+}
+
+impl<ShL, ShR, EL, ER> BitOr<Tensor<bool, ShR, ER>> for Tensor<bool, ShL, EL>
+where
+    ShL: Shape,
+    ShR: Shape,
+    ShL: BroadcastShape<ShR>,
+    <ShL as BroadcastShape<ShR>>::Output: BroadcastMap<ShL> + BroadcastMap<ShR>,
+    [(); <ShL as BroadcastShape<ShR>>::Output::RANK]:,
+{
+    type Output = Tensor<bool, <ShL as BroadcastShape<ShR>>::Output, ZipExpr<EL, ER, OrKernel>>;
+
+    fn bitor(self, rhs: Tensor<bool, ShR, ER>) -> Self::Output {
+        Tensor::wrap(ZipExpr {
+            left: self.expr,
+            right: rhs.expr,
+            kernel: OrKernel,
+        })
+    }
+}
+
+impl<Sh: Shape, E> Not for Tensor<bool, Sh, E> {
+    type Output = Tensor<bool, Sh, MapExpr<E, NotKernel>>;
+
+    fn not(self) -> Self::Output {
+        self.map(NotKernel)
+    }
+}
@@ -0,0 +1,285 @@
+use super::{Evaluator, Host, Lower, PackDense, Tensor};
+use algebra::{
+    BinaryKernel, BroadcastMap, ContractExpr, ContractIndices, ContractShape, Contracted, Data,
+    DequantizeKernel, GroupedExpr, IndexOf, MulKernel, QMulKernel, QuantParams, QuantizeKernel,
+    Quantized, Shape,
+};
+use backend::Backend;
+use std::sync::Arc;
+
+impl<F: Data, Sh: Shape, E> Tensor<F, Sh, E> {
+    // `kernel(params[i], x)` for every `x` at index `i` along `axis`; one shared parameter
+    // when `axis` is `None`.
+    pub fn grouped<P: Data, K: BinaryKernel<P, F>>(
+        self,
+        params: Arc<Vec<P>>,
+        axis: Option<usize>,
+        kernel: K,
+    ) -> Tensor<K::Output, Sh, GroupedExpr<E, P, K>> {
+        Tensor::wrap(GroupedExpr {
+            op: self.expr,
+            params,
+            axis,
+            kernel,
+        })
+    }
+}
+
+impl<Sh: Shape, E> Tensor<f32, Sh, E> {
+    pub fn quantize<Q: Quantized>(
+        self,
+        params: QuantParams,
+    ) -> Tensor<Q, Sh, GroupedExpr<E, QuantParams, QuantizeKernel<Q>>> {
+        self.grouped(Arc::new(vec![params]), None, QuantizeKernel::new())
+    }
+
+    // One set of parameters per index of the `Ax` axis, e.g. per output channel.
+    pub fn quantize_along<Ax, Q: Quantized>(
+        self,
+        params: Vec<QuantParams>,
+    ) -> Tensor<Q, Sh, GroupedExpr<E, QuantParams, QuantizeKernel<Q>>>
+    where
+        Sh: IndexOf<Ax>,
+    {
+        let axis = <Sh as IndexOf<Ax>>::INDEX;
+        self.grouped(Arc::new(params), Some(axis), QuantizeKernel::new())
+    }
+}
+
+impl<Q: Quantized, Sh: Shape, E> Tensor<Q, Sh, E> {
+    pub fn dequantize(
+        self,
+        params: QuantParams,
+    ) -> Tensor<f32, Sh, GroupedExpr<E, QuantParams, DequantizeKernel>> {
+        self.grouped(Arc::new(vec![params]), None, DequantizeKernel)
+    }
+
+    pub fn dequantize_along<Ax>(
+        self,
+        params: Vec<QuantParams>,
+    ) -> Tensor<f32, Sh, GroupedExpr<E, QuantParams, DequantizeKernel>>
+    where
+        Sh: IndexOf<Ax>,
+    {
+        let axis = <Sh as IndexOf<Ax>>::INDEX;
+        self.grouped(Arc::new(params), Some(axis), DequantizeKernel)
+    }
+}
+
+/// Materialized int8 tensor: `i8`/`u8` values plus their affine parameters, either one set
+/// for the whole tensor or one per index of a labelled axis.
+/// A quarter of the memory of `f32`; contractions run in integers and accumulate in `i32`.
+#[derive(Debug, Clone)]
+pub struct QTensor<Q, Sh: Shape>
+where
+    [(); Sh::RANK]:,
+{
+    values: Tensor<Q, Sh, Host<Q, { Sh::RANK }>>,
+    params: Arc<Vec<QuantParams>>,
+    axis: Option<usize>,
+}
+
+impl<Q: Quantized, Sh: Shape> QTensor<Q, Sh>
+where
+    [(); Sh::RANK]:,
+{
+    pub fn quantize<B, E>(x: Tensor<f32, Sh, E>, params: QuantParams, backend: &mut B) -> Self
+    where
+        B: Backend,
+        E: Evaluator<B, { Sh::RANK }, Data = f32>,
+    {
+        Self::materialize(x.quantize(params), backend)
+    }
+
+    pub fn quantize_along<Ax, B, E>(
+        x: Tensor<f32, Sh, E>,
+        params: Vec<QuantParams>,
+        backend: &mut B,
+    ) -> Self
+    where
+        Sh: IndexOf<Ax>,
+        B: Backend,
+        E: Evaluator<B, { Sh::RANK }, Data = f32>,
+    {
+        Self::materialize(x.quantize_along::<Ax, Q>(params), backend)
+    }
+
+    fn materialize<B, E>(
+        q: Tensor<Q, Sh, GroupedExpr<E, QuantParams, QuantizeKernel<Q>>>,
+        backend: &mut B,
+    ) -> Self
+    where
+        B: Backend,
+        E: Evaluator<B, { Sh::RANK }, Data = f32>,
+    {
+        let view = q.expr.eval(backend);
+        let dense = Lower::<PackDense, B>::lower(&view, backend);
+        Self {
+            values: Tensor::wrap(Host::new(Arc::new(backend.to_host(&dense)), view.shape)),
+            params: q.expr.params,
+            axis: q.expr.axis,
+        }
+    }
+
+    pub fn values(&self) -> &Tensor<Q, Sh, Host<Q, { Sh::RANK }>> {
+        &self.values
+    }
+
+    // Shares the storage; `Tensor` itself is only `Clone` for `Clone` labels.
+    fn tensor(&self) -> Tensor<Q, Sh, Host<Q, { Sh::RANK }>> {
+        Tensor::wrap(self.values.expr.clone())
+    }
+
+    pub fn params(&self) -> &[QuantParams] {
+        &self.params
+    }
+
+    // Index of the quantized axis, `None` for per-tensor parameters
+    pub fn axis(&self) -> Option<usize> {
+        self.axis
+    }
+
+    pub fn dequantize(
+        &self,
+    ) -> Tensor<f32, Sh, GroupedExpr<Host<Q, { Sh::RANK }>, QuantParams, DequantizeKernel>> {
+        self.tensor()
+            .grouped(self.params.clone(), self.axis, DequantizeKernel)
+    }
+
+    // The zero point is subtracted inside the integer products, so it has to be the same
+    // across the quantized axis. Symmetric per-channel weights satisfy this with zero.
+    fn zero_point(&self) -> i32 {
+        let zero = self.params[0].zero_point;
+        assert!(
+            self.params.iter().all(|p| p.zero_point == zero),
+            "contract: per-axis zero points must agree"
+        );
+        zero
+    }
+
+    fn scales(&self) -> Arc<Vec<f32>> {
+        Arc::new(self.params.iter().map(|p| p.scale).collect())
+    }
+
+    /// `Tensor::contract` over quantized operands: the products are exact `i32`s,
+    /// accumulated in `i32`, and scaled to `f32` once per output element.
+    /// A per-axis operand must not be contracted along its quantized axis.
+    #[allow(clippy::type_complexity)]
+    pub fn contract<Shared, ShR, Q2>(
+        &self,
+        rhs: &QTensor<Q2, ShR>,
+    ) -> Tensor<
+        f32,
+        Contracted<Sh, ShR, Shared>,
+        GroupedExpr<
+            GroupedExpr<
+                ContractExpr<
+                    Host<Q, { Sh::RANK }>,
+                    Host<Q2, { ShR::RANK }>,
+                    QMulKernel,
+                    { Shared::RANK },
+                    { Sh::RANK },
+                    { ShR::RANK },
+                    { Contracted::<Sh, ShR, Shared>::RANK },
+                >,
+                f32,
+                MulKernel,
+            >,
+            f32,
+            MulKernel,
+        >,
+    >
+    where
+        [(); ShR::RANK]:,
+        Q2: Quantized,
+        Shared: Shape,
+        ShR: Shape,
+        Sh: ContractIndices<Shared>,
+        ShR: ContractIndices<Shared>,
+        (): ContractShape<Sh, ShR, Shared>,
+        Contracted<Sh, ShR, Shared>: BroadcastMap<Sh> + BroadcastMap<ShR>,
+    {
+        let mul = QMulKernel {
+            lhs_zero: self.zero_point(),
+            rhs_zero: rhs.zero_point(),
+        };
+        let acc = self
+            .tensor()
+            .contract_with::<Shared, _, _, _, _>(rhs.tensor(), mul);
+
+        // Position of a quantized axis in the output
+        let output_axis = |axis: Option<usize>, map: &[Option<usize>]| {
+            axis.map(|a| {
+                map.iter()
+                    .position(|&src| src == Some(a))
+                    .expect("contract: cannot contract along a per-axis quantized axis")
+            })
+        };
+        let lhs_axis = output_axis(self.axis, &acc.expr.left_map);
+        let rhs_axis = output_axis(rhs.axis, &acc.expr.right_map);
+
+        acc.grouped(rhs.scales(), rhs_axis, MulKernel)
+            .grouped(self.scales(), lhs_axis, MulKernel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QTensor;
+    use crate::Tensor;
+    use algebra::{Axes, QuantParams, make_labels};
+    use backend::GenericBackend;
+
+    #[test]
+    fn test_int8_inference() {
+        make_labels!(Batch, Feature, Out);
+        let mut backend = GenericBackend::new();
+
+        let x: [f32; 8] = [0.5, -1.2, 2.0, 0.0, 0.3, -0.7, 1.1, 1.9];
+        let w: [f32; 12] = [
+            0.2, -0.4, 0.05, 0.9, -0.3, 0.1, 0.01, -0.02, 0.03, 0.6, 0.0, -0.8,
+        ];
+        let input = Tensor::new(x.to_vec(), [2, 4]).into_named::<Axes!(Batch, Feature)>();
+        let weights = Tensor::new(w.to_vec(), [4, 3]).into_named::<Axes!(Feature, Out)>();
+
+        // Activations: asymmetric u8 per tensor. Weights: symmetric i8 per output column.
+        let qx = QTensor::<u8, _>::quantize(
+            input.clone(),
+            QuantParams::from_range::<u8>(-1.2, 2.0),
+            &mut backend,
+        );
+        let absmax = |j: usize| (0..4).map(|i| w[i * 3 + j].abs()).fold(0.0, f32::max);
+        let per_column = (0..3)
+            .map(|j| QuantParams::symmetric::<i8>(absmax(j)))
+            .collect();
+        let qw = QTensor::<i8, _>::quantize_along::<Out, _, _>(
+            weights.clone(),
+            per_column,
+            &mut backend,
+        );
+        assert_eq!(qw.axis(), Some(1));
+
+        // Round trip is within half a step of each element's own scale.
+        let back = qw.dequantize().to_vec(&mut backend);
+        for (i, (&b, &w)) in back.iter().zip(&w).enumerate() {
+            assert!((b - w).abs() <= qw.params()[i % 3].scale / 2.0);
+        }
+
+        // The integer GEMM equals dequantize-then-multiply up to f32 rounding ...
+        let y = qx
+            .contract::<Axes!(Feature), _, _>(&qw)
+            .to_vec(&mut backend);
+        let reference = qx
+            .dequantize()
+            .contract::<Axes!(Feature), _, _>(qw.dequantize())
+            .to_vec(&mut backend);
+        // ... and the float model up to the quantization error.
+        let exact = input
+            .contract::<Axes!(Feature), _, _>(weights)
+            .to_vec(&mut backend);
+        for ((&y, &r), &e) in y.iter().zip(&reference).zip(&exact) {
+            assert!((y - r).abs() < 1e-5, "{y} vs {r}");
+            assert!((y - e).abs() < 0.02, "{y} vs {e}");
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use super::{Differentiable, Evaluator, GradientTape, LeafAdjoint, Lift, Lower, PackDense};
 use algebra::{
-    BroadcastExpr, BroadcastMap, ConstExpr, Data, DynRank, MapExpr, Permutation, Real, ReshapeExpr,
-    ScaleKernel, Semiring, Shape, SliceExpr, TransposeExpr,
+    AddKernel, ApproxEq, BinaryKernel, BridgeExpr, BroadcastExpr, BroadcastMap, BrownianBridge,
+    CDistExpr, Cons, ConstExpr, ContractExpr, ContractIndices, ContractShape, Contracted,
+    CounterRng, Data, DistanceMetric, Distribution, DynRank, EqKernel, GeKernel, GtKernel, IfExpr,
+    IndexOf, Label, LeKernel, LowDiscrepancy, LtKernel, MapExpr, MulKernel, Narrow, NarrowKernel,
+    Nil, Normal, Permutation, Philox, Promote, QuasiRandomExpr, RandExpr, Real, ReduceExpr,
+    ReduceKernel, Remove, ReshapeExpr, ScaleKernel, ScanExpr, Semiring, Shape, SliceExpr,
+    StreamExpr, StreamKernel, Tolerance, TransposeExpr, UnaryKernel, Uniform, WidenKernel, ZipExpr,
 };
 use backend::Backend;
 use std::{marker::PhantomData, sync::Arc};
@@ -179,7 +184,7 @@ where
 //    - Goal: Linear scaling with dimension (O(d*k)) instead of exponential (O(d^k)).
 // 2. Quantum Amplitude Estimation (QAE)
 //    - Quadratic speedup over Classical Monte Carlo for Derivative Pricing.
-//    - Requires: `Complex<F>` support in `Data` trait.
+//    - `Complex<F>` amplitudes are available in algebra; state vectors are `Tensor<Complex<F>, _>`.
 //    - Requires: Gate abstractions (Hadamard, Phase Oracle) over the Tensor backend.
 //    - Use Case: Faster CVA/XVA calculations and Risk Management.
 // 3. Clifford Algebra / Geometric Algebra
@@ -199,6 +204,30 @@ impl<F: Data, Sh: Shape, E> Tensor<F, Sh, E> {
         }
     }
 
+    // Element-wise kernel application, e.g. `x.map(NormCdfKernel)`
+    pub fn map<K: UnaryKernel<F>>(self, kernel: K) -> Tensor<K::Output, Sh, MapExpr<E, K>> {
+        Tensor::wrap(MapExpr {
+            op: self.expr,
+            kernel,
+        })
+    }
+
+    // Rounds into a storage type, e.g. `x.narrow::<F16>()`
+    pub fn narrow<T: Data>(self) -> Tensor<T, Sh, MapExpr<E, NarrowKernel<T>>>
+    where
+        F: Narrow<T>,
+    {
+        self.map(NarrowKernel::new())
+    }
+
+    // Back to the compute type, e.g. `F16` -> `f32`
+    pub fn widen(self) -> Tensor<<F as Promote<F>>::Output, Sh, MapExpr<E, WidenKernel>>
+    where
+        F: Promote<F>,
+    {
+        self.map(WidenKernel)
+    }
+
     pub fn align<NewShape>(self) -> Tensor<F, NewShape, TransposeExpr<E, { NewShape::RANK }>>
     where
         NewShape: Shape,
@@ -242,6 +271,47 @@ impl<F: Data, Sh: Shape, E> Tensor<F, Sh, E> {
         })
     }
 
+    /// `contract` with a custom product, e.g. mixed operand types or integer GEMM with
+    /// widened accumulation. Products are summed with `SumKernel`.
+    #[allow(clippy::type_complexity)]
+    pub fn contract_with<Shared, ShR, FR, ER, M>(
+        self,
+        rhs: Tensor<FR, ShR, ER>,
+        mul: M,
+    ) -> Tensor<
+        M::Output,
+        Contracted<Sh, ShR, Shared>,
+        ContractExpr<
+            E,
+            ER,
+            M,
+            { Shared::RANK },
+            { Sh::RANK },
+            { ShR::RANK },
+            { Contracted::<Sh, ShR, Shared>::RANK },
+        >,
+    >
+    where
+        Shared: Shape,
+        ShR: Shape,
+        FR: Data,
+        M: BinaryKernel<F, FR>,
+        Sh: ContractIndices<Shared>,
+        ShR: ContractIndices<Shared>,
+        (): ContractShape<Sh, ShR, Shared>,
+        Contracted<Sh, ShR, Shared>: BroadcastMap<Sh> + BroadcastMap<ShR>,
+    {
+        Tensor::wrap(ContractExpr {
+            left: self.expr,
+            right: rhs.expr,
+            mul,
+            left_axes: <Sh as ContractIndices<Shared>>::indices(),
+            right_axes: <ShR as ContractIndices<Shared>>::indices(),
+            left_map: <Contracted<Sh, ShR, Shared> as BroadcastMap<Sh>>::mapping(),
+            right_map: <Contracted<Sh, ShR, Shared> as BroadcastMap<ShR>>::mapping(),
+        })
+    }
+
     pub fn collect<B: Backend>(&self, backend: &mut B) -> Base<B::Storage<F>, F, { Sh::RANK }>
     where
         E: Evaluator<B, { Sh::RANK }, Data = F>,
@@ -257,17 +327,169 @@ impl<F: Data, Sh: Shape, E> Tensor<F, Sh, E> {
         let dense = Lower::<PackDense, B>::lower(&view, backend);
         backend.to_host(&dense)
     }
+
+    /// Element-wise `ApproxEq` against `other`.
+    /// Returns the index of the first element (row-major) outside the tolerance.
+    pub fn all_close<B: Backend, E2>(
+        &self,
+        other: &Tensor<F, Sh, E2>,
+        tol: Tolerance,
+        backend: &mut B,
+    ) -> Result<(), [usize; Sh::RANK]>
+    where
+        F: ApproxEq,
+        E: Evaluator<B, { Sh::RANK }, Data = F>,
+        E2: Evaluator<B, { Sh::RANK }, Data = F>,
+    {
+        let l_view = self.expr.eval(backend);
+        let r_view = other.expr.eval(backend);
+        assert_eq!(l_view.shape, r_view.shape, "all_close shape mismatch");
+
+        let l_dense = Lower::<PackDense, B>::lower(&l_view, backend);
+        let r_dense = Lower::<PackDense, B>::lower(&r_view, backend);
+        let lhs = backend.to_host(&l_dense);
+        let rhs = backend.to_host(&r_dense);
+
+        match lhs.iter().zip(&rhs).position(|(l, r)| !l.approx_eq(r, tol)) {
+            None => Ok(()),
+            Some(mut linear) => {
+                let mut index = [0; Sh::RANK];
+                for i in (0..Sh::RANK).rev() {
+                    index[i] = linear % l_view.shape[i];
+                    linear /= l_view.shape[i];
+                }
+                Err(index)
+            }
+        }
+    }
+
+    /// Steps a stateful `kernel` along `Ax`, restarting it for every lane, e.g.
+    /// `shocks.stream::<Time, _>(gbm)` turns [Path, Time] shocks into price paths.
+    /// With a `DifferentiableStreamKernel`, `forward` pulls gradients back to the value each
+    /// lane started from, e.g. pathwise deltas by the spot of every path.
+    pub fn stream<Ax, K>(self, kernel: K) -> Tensor<K::Output, Sh, StreamExpr<E, K>>
+    where
+        Sh: IndexOf<Ax>,
+        K: StreamKernel<F>,
+    {
+        Tensor::wrap(StreamExpr {
+            op: self.expr,
+            kernel,
+            axis: <Sh as IndexOf<Ax>>::INDEX,
+        })
+    }
 }
 
 // Algebraic Ops
 impl<F: Semiring, Sh: Shape, E> Tensor<F, Sh, E> {
-    // TODO: Matrix Multiplication (.matmul / .dot).
-    // This requires `ContractExpr`.
-    // It's the most computationally intensive operation and usually delegates to BLAS/cuBLAS.
+    /// Contracts the `Shared` axes of `self` and `rhs`: multiplies along them and sums with
+    /// the semiring `+`. The result keeps the remaining axes of `self`, then those of `rhs`;
+    /// an uncontracted axis present on both sides is a batch axis and appears once.
+    #[allow(clippy::type_complexity)]
+    pub fn contract<Shared, ShR, ER>(
+        self,
+        rhs: Tensor<F, ShR, ER>,
+    ) -> Tensor<
+        F,
+        Contracted<Sh, ShR, Shared>,
+        ContractExpr<
+            E,
+            ER,
+            MulKernel,
+            { Shared::RANK },
+            { Sh::RANK },
+            { ShR::RANK },
+            { Contracted::<Sh, ShR, Shared>::RANK },
+        >,
+    >
+    where
+        Shared: Shape,
+        ShR: Shape,
+        Sh: ContractIndices<Shared>,
+        ShR: ContractIndices<Shared>,
+        (): ContractShape<Sh, ShR, Shared>,
+        Contracted<Sh, ShR, Shared>: BroadcastMap<Sh> + BroadcastMap<ShR>,
+        MulKernel: BinaryKernel<F, F>,
+    {
+        Tensor::wrap(self.contract_with(rhs, MulKernel).expr)
+    }
+
+    /// Inclusive scan along the last axis: `out[t] = kernel(out[t - 1], self[t])`,
+    /// starting from `init`. The semiring decides what "cumulative" means.
+    pub fn scan<K>(self, init: F, kernel: K) -> Tensor<F, Sh, ScanExpr<E, F, K>>
+    where
+        K: BinaryKernel<F, F, Output = F>,
+    {
+        Tensor::wrap(ScanExpr {
+            op: self.expr,
+            init,
+            kernel,
+        })
+    }
+
+    /// Folds the `Ax` axis away with `kernel`, e.g. `x.reduce::<Time, _>(MedianKernel)`
+    /// for a cross-section of per-asset medians.
+    #[allow(clippy::type_complexity)]
+    pub fn reduce<Ax, K>(
+        self,
+        kernel: K,
+    ) -> Tensor<K::Output, <Sh as Remove<Ax>>::Remainder, ReduceExpr<E, K, { Sh::RANK }>>
+    where
+        Sh: IndexOf<Ax> + Remove<Ax>,
+        K: ReduceKernel<F>,
+    {
+        Tensor::wrap(ReduceExpr {
+            op: self.expr,
+            axis: <Sh as IndexOf<Ax>>::INDEX,
+            kernel,
+        })
+    }
+
+    /// All-pairs distances between the `RowsA` rows of `self` and the `RowsB` rows of `rhs`,
+    /// measured over the shared `Feature` axis. Both operands are rank 2; e.g.
+    /// `today.cdist::<Day, Regime, Asset, _, _, _>(centroids, Correlation)`.
+    #[allow(clippy::type_complexity)]
+    pub fn cdist<RowsA, RowsB, Feature, ShR, ER, M>(
+        self,
+        rhs: Tensor<F, ShR, ER>,
+        metric: M,
+    ) -> Tensor<F, Cons<RowsA, Cons<RowsB, Nil>>, CDistExpr<E, ER, M>>
+    where
+        RowsA: Label,
+        RowsB: Label,
+        Sh: IndexOf<RowsA> + IndexOf<Feature>,
+        ShR: Shape + IndexOf<RowsB> + IndexOf<Feature>,
+        M: DistanceMetric<F>,
+    {
+        Tensor::wrap(CDistExpr {
+            left: self.expr,
+            right: rhs.expr,
+            metric,
+            left_axes: [
+                <Sh as IndexOf<RowsA>>::INDEX,
+                <Sh as IndexOf<Feature>>::INDEX,
+            ],
+            right_axes: [
+                <ShR as IndexOf<RowsB>>::INDEX,
+                <ShR as IndexOf<Feature>>::INDEX,
+            ],
+        })
+    }
+
+    pub fn cumsum(self) -> Tensor<F, Sh, ScanExpr<E, F, AddKernel>>
+    where
+        AddKernel: BinaryKernel<F, F, Output = F>,
+    {
+        self.scan(F::zero(), AddKernel)
+    }
+
+    pub fn cumprod(self) -> Tensor<F, Sh, ScanExpr<E, F, MulKernel>>
+    where
+        MulKernel: BinaryKernel<F, F, Output = F>,
+    {
+        self.scan(F::one(), MulKernel)
+    }
 
-    // TODO: Scan (Prefix Sum / CumSum).
-    // `ScanExpr` is needed for `cumsum`, `cumprod`, and RNNs.
-    // It is fundamentally sequential (O(N)) unless parallel prefix sum algorithms are used.
     pub fn scale(self, factor: F) -> Tensor<F, Sh, MapExpr<E, ScaleKernel<F>>> {
         Tensor::wrap(MapExpr {
             op: self.expr,
@@ -286,8 +508,79 @@ impl<F: Semiring, Sh: Shape, E> Tensor<F, Sh, E> {
     }
 }
 
+// Comparisons, element-wise. Size-1 axes (e.g. `Tensor::splat`) broadcast at evaluation.
+impl<F: Data, Sh: Shape, E> Tensor<F, Sh, E> {
+    fn compare<ER, K>(
+        self,
+        rhs: Tensor<F, Sh, ER>,
+        kernel: K,
+    ) -> Tensor<bool, Sh, ZipExpr<E, ER, K>>
+    where
+        K: BinaryKernel<F, F, Output = bool>,
+    {
+        Tensor::wrap(ZipExpr {
+            left: self.expr,
+            right: rhs.expr,
+            kernel,
+        })
+    }
+
+    pub fn gt<ER>(self, rhs: Tensor<F, Sh, ER>) -> Tensor<bool, Sh, ZipExpr<E, ER, GtKernel>>
+    where
+        GtKernel: BinaryKernel<F, F, Output = bool>,
+    {
+        self.compare(rhs, GtKernel)
+    }
+
+    pub fn ge<ER>(self, rhs: Tensor<F, Sh, ER>) -> Tensor<bool, Sh, ZipExpr<E, ER, GeKernel>>
+    where
+        GeKernel: BinaryKernel<F, F, Output = bool>,
+    {
+        self.compare(rhs, GeKernel)
+    }
+
+    pub fn lt<ER>(self, rhs: Tensor<F, Sh, ER>) -> Tensor<bool, Sh, ZipExpr<E, ER, LtKernel>>
+    where
+        LtKernel: BinaryKernel<F, F, Output = bool>,
+    {
+        self.compare(rhs, LtKernel)
+    }
+
+    pub fn le<ER>(self, rhs: Tensor<F, Sh, ER>) -> Tensor<bool, Sh, ZipExpr<E, ER, LeKernel>>
+    where
+        LeKernel: BinaryKernel<F, F, Output = bool>,
+    {
+        self.compare(rhs, LeKernel)
+    }
+
+    pub fn eq<ER>(self, rhs: Tensor<F, Sh, ER>) -> Tensor<bool, Sh, ZipExpr<E, ER, EqKernel>>
+    where
+        EqKernel: BinaryKernel<F, F, Output = bool>,
+    {
+        self.compare(rhs, EqKernel)
+    }
+}
+
+impl<Sh: Shape, E> Tensor<bool, Sh, E> {
+    /// Picks `then_` where the mask is set and `else_` elsewhere, e.g.
+    /// `Tensor::where_(momentum.gt(Tensor::splat(0.0)), Tensor::splat(1.0), Tensor::splat(0.0))`.
+    /// Both branches are evaluated in full; the selection itself is branchless.
+    pub fn where_<F: Data, ET, EE>(
+        self,
+        then_: Tensor<F, Sh, ET>,
+        else_: Tensor<F, Sh, EE>,
+    ) -> Tensor<F, Sh, IfExpr<E, ET, EE>> {
+        Tensor::wrap(IfExpr {
+            cond: self.expr,
+            then_: then_.expr,
+            else_: else_.expr,
+        })
+    }
+}
+
 // Calculus Ops (Gradients, Physics)
 impl<F: Real, Sh: Shape, E> Tensor<F, Sh, E> {
+    #[allow(clippy::type_complexity)]
     pub fn forward<B: Backend>(
         &self,
         backend: &mut B,
@@ -301,6 +594,20 @@ impl<F: Real, Sh: Shape, E> Tensor<F, Sh, E> {
         let (res, adjoint) = self.expr.forward(backend);
         (res, GradientTape::new(adjoint))
     }
+
+    /// Reads standard normals along `Ax` in bridge order and returns the Brownian
+    /// increments of each path in time order, e.g. for Sobol points mapped through
+    /// `NormInvCdfKernel`.
+    pub fn brownian_bridge<Ax>(self, bridge: BrownianBridge<F>) -> Tensor<F, Sh, BridgeExpr<E, F>>
+    where
+        Sh: IndexOf<Ax>,
+    {
+        Tensor::wrap(BridgeExpr {
+            op: self.expr,
+            bridge,
+            axis: <Sh as IndexOf<Ax>>::INDEX,
+        })
+    }
 }
 
 impl<F: Data> Tensor<F, DynRank<0>, ConstExpr<F>> {
@@ -309,6 +616,13 @@ impl<F: Data> Tensor<F, DynRank<0>, ConstExpr<F>> {
     }
 }
 
+impl<F: Data, Sh: Shape> Tensor<F, Sh, ConstExpr<F>> {
+    // The same value at every index of any shape
+    pub fn splat(value: F) -> Self {
+        Tensor::wrap(ConstExpr(value))
+    }
+}
+
 impl<F: Data, const R: usize> Tensor<F, DynRank<R>, Host<F, R>> {
     pub fn new(data: Vec<F>, shape: [usize; R]) -> Self {
         debug_assert_eq!(data.len(), shape.iter().product::<usize>());
@@ -349,6 +663,70 @@ impl<F: Data, const R: usize> Tensor<F, DynRank<R>, Host<F, R>> {
     }
 }
 
+impl<G: CounterRng, D: Distribution, const R: usize>
+    Tensor<D::Output, DynRank<R>, RandExpr<G, D, R>>
+{
+    // Lazily sampled: nothing is drawn until evaluation, and every evaluation with the
+    // same `rng` yields the same values.
+    pub fn sample(rng: G, dist: D, shape: [usize; R]) -> Self {
+        Tensor::wrap(RandExpr {
+            rng,
+            dist,
+            shape,
+            offset: 0,
+        })
+    }
+
+    // Starts at sample index `offset` of the stream, e.g. to draw the next batch of paths
+    // without overlapping the previous one.
+    pub fn skip(mut self, offset: u64) -> Self {
+        self.expr.offset = offset;
+        self
+    }
+
+    pub fn into_named<NewSh: Shape>(self) -> Tensor<D::Output, NewSh, RandExpr<G, D, R>> {
+        debug_assert_eq!(NewSh::RANK, R, "Rank mismatch");
+        Tensor::wrap(self.expr)
+    }
+}
+
+impl<S: LowDiscrepancy, F: Real> Tensor<F, DynRank<2>, QuasiRandomExpr<S, F>> {
+    // [paths, seq.dims()]: one low-discrepancy point per path.
+    pub fn quasi_random(seq: S, paths: usize) -> Self {
+        Tensor::wrap(QuasiRandomExpr {
+            seq,
+            paths,
+            offset: 0,
+            _marker: PhantomData,
+        })
+    }
+
+    // Starts at point `offset`, e.g. for the next batch of paths.
+    pub fn skip(mut self, offset: u64) -> Self {
+        self.expr.offset = offset;
+        self
+    }
+
+    pub fn into_named<NewSh: Shape>(self) -> Tensor<F, NewSh, QuasiRandomExpr<S, F>> {
+        debug_assert_eq!(NewSh::RANK, 2, "Rank mismatch");
+        Tensor::wrap(self.expr)
+    }
+}
+
+impl<F: Real, const R: usize> Tensor<F, DynRank<R>, RandExpr<Philox, Uniform<F>, R>> {
+    /// U[0, 1) samples from stream 0 of `seed`.
+    pub fn rand(shape: [usize; R], seed: u64) -> Self {
+        Self::sample(Philox::new(seed, 0), Uniform::standard(), shape)
+    }
+}
+
+impl<F: Real, const R: usize> Tensor<F, DynRank<R>, RandExpr<Philox, Normal<F>, R>> {
+    /// N(0, 1) samples from stream 0 of `seed`.
+    pub fn randn(shape: [usize; R], seed: u64) -> Self {
+        Self::sample(Philox::new(seed, 0), Normal::standard(), shape)
+    }
+}
+
 impl<F: Data> From<Vec<F>> for Tensor<F, DynRank<1>, Host<F, 1>> {
     fn from(vec: Vec<F>) -> Self {
         Tensor::wrap(<Vec<F> as Lift<F>>::lift(vec))
@@ -377,23 +755,39 @@ macro_rules! tensor {
         $crate::Tensor::scalar(val)
     }};
 
-    ($($x:expr),+ $(,)?) => {{
+    ($([$($x:expr),* $(,)?]),+ $(,)?) => {{
         use ::algebra::TradingFloat;
 
-        $crate::Tensor::from([
-            $(TradingFloat::try_from($x).expect("Invalid float")),+
+        $crate::Tensor::<TradingFloat, ::algebra::DynRank<2>, $crate::Host<TradingFloat, 2>>::from([
+            $([$(TradingFloat::try_from($x).expect("Invalid float")),*]),+
         ])
     }};
 
-    ($([$($x:expr),* $(,)?]),+ $(,)?) => {{
+    ($($x:expr),+ $(,)?) => {{
         use ::algebra::TradingFloat;
 
-        $crate::Tensor::from([
-            $([$(TradingFloat::try_from($x).expect("Invalid float")),*]),+
+        $crate::Tensor::<TradingFloat, ::algebra::DynRank<1>, $crate::Host<TradingFloat, 1>>::from([
+            $(TradingFloat::try_from($x).expect("Invalid float")),+
         ])
     }};
 }
 
+#[macro_export]
+macro_rules! assert_all_close {
+    ($backend:expr, $left:expr, $right:expr $(,)?) => {
+        $crate::assert_all_close!($backend, $left, $right, ::algebra::Tolerance::DEFAULT)
+    };
+    ($backend:expr, $left:expr, $right:expr, $tol:expr $(,)?) => {{
+        let tol = $tol;
+        if let Err(index) = $left.all_close(&$right, tol, $backend) {
+            panic!(
+                "assertion `left ≈ right` failed ({:?}): first mismatch at index {:?}",
+                tol, index
+            );
+        }
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use backend::GenericBackend;
@@ -412,4 +806,694 @@ mod tests {
         assert_eq!(result[1].to_f64(), 6.0);
         assert_eq!(result[2].to_f64(), 7.0);
     }
+
+    #[test]
+    fn test_map_normal_quantiles() {
+        use algebra::{NormCdfKernel, NormInvCdfKernel, Tolerance, TradingFloat, assert_approx_eq};
+
+        let mut backend = GenericBackend::new();
+        let p = tensor![0.01, 0.025, 0.5, 0.975];
+        let z = p.clone().map(NormInvCdfKernel).to_vec(&mut backend);
+        assert_approx_eq!(
+            z[1],
+            TradingFloat::new(-1.959963984540054),
+            Tolerance::Ulps(8)
+        );
+        assert_eq!(z[2], TradingFloat::ZERO);
+
+        let round_trip = tensor![0.01, 0.025, 0.5, 0.975]
+            .map(NormInvCdfKernel)
+            .map(NormCdfKernel);
+        assert_eq!(
+            round_trip.all_close(&p, Tolerance::Relative(1e-14), &mut backend),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_half_storage_round_trip() {
+        use super::Tensor;
+        use algebra::{BF16, F16};
+
+        let mut backend = GenericBackend::new();
+        let prices = Tensor::new(vec![101.25f32, 99.5, 0.1, 65504.0], [2, 2]);
+
+        let stored = prices.clone().narrow::<F16>();
+        let wide = stored.widen().to_vec(&mut backend);
+        assert_eq!(&wide[..2], &[101.25, 99.5]);
+        assert_eq!(wide[2], 1638.0 / 16384.0);
+        assert_eq!(wide[3], 65504.0);
+
+        let bf = prices.narrow::<BF16>().to_vec(&mut backend);
+        assert_eq!(bf[0], BF16::from_f32(101.0));
+    }
+
+    #[test]
+    fn test_where_signals() {
+        use super::Tensor;
+        use algebra::{Axes, make_labels};
+
+        make_labels!(Time, Asset);
+        let mut backend = GenericBackend::new();
+        let momentum = Tensor::new(vec![0.4, -0.2, 0.0, 1.3, -0.7, 0.1], [3, 2])
+            .into_named::<Axes!(Time, Asset)>();
+
+        // Long if momentum > 0 else flat
+        let long = momentum.clone().gt(Tensor::splat(0.0));
+        let position = Tensor::where_(long.clone(), Tensor::splat(1.0), Tensor::splat(0.0));
+        assert_eq!(
+            position.to_vec(&mut backend),
+            [1.0, 0.0, 0.0, 1.0, 0.0, 1.0]
+        );
+
+        // Per-asset thresholds broadcast along time; branches can be full tensors.
+        let threshold = Tensor::new(vec![0.2, -0.5], [2])
+            .into_named::<Axes!(Asset)>()
+            .expand::<Axes!(Time, Asset)>([3, 2]);
+        let strong = momentum.clone().ge(threshold);
+        let scaled = Tensor::where_(strong.clone(), momentum.clone(), Tensor::splat(-1.0));
+        assert_eq!(
+            scaled.to_vec(&mut backend),
+            [0.4, -0.2, -1.0, 1.3, -1.0, 0.1]
+        );
+
+        let flat = momentum.eq(Tensor::splat(0.0));
+        let mixed = (long.clone() & !strong.clone()) | flat;
+        assert_eq!(
+            mixed.to_vec(&mut backend),
+            [false, false, true, false, false, false]
+        );
+        assert_eq!(
+            (long | strong).to_vec(&mut backend),
+            [true, true, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_axis_reductions() {
+        use super::Tensor;
+        use algebra::{
+            ArgMaxKernel, Axes, MadKernel, MeanKernel, MedianKernel, TradingFloat, make_labels,
+        };
+
+        make_labels!(Time, Asset);
+        let mut backend = GenericBackend::new();
+        let close = |got: Vec<TradingFloat>, expected: &[f64]| {
+            assert_eq!(got.len(), expected.len());
+            for (g, &e) in got.iter().zip(expected) {
+                assert!((g.to_f64() - e).abs() < 1e-12, "{g:?} vs {e}");
+            }
+        };
+        let data: [f64; 12] = [
+            0.01, -0.02, 0.03, //
+            0.02, 0.00, -0.01, //
+            -0.50, 0.01, 0.02, //
+            0.03, 0.02, 0.00,
+        ];
+        let returns = Tensor::new(data.map(TradingFloat::new).to_vec(), [4, 3])
+            .into_named::<Axes!(Time, Asset)>();
+
+        // Per asset, over time: the crash at t = 2 drags the mean but not the median.
+        let mean = returns.clone().reduce::<Time, _>(MeanKernel);
+        close(mean.to_vec(&mut backend), &[-0.11, 0.0025, 0.01]);
+        let median = returns.clone().reduce::<Time, _>(MedianKernel);
+        close(median.to_vec(&mut backend), &[0.015, 0.005, 0.01]);
+
+        // Cross-sections: one value per time step.
+        let best = returns.clone().reduce::<Asset, _>(ArgMaxKernel);
+        assert_eq!(best.to_vec(&mut backend), [2, 0, 2, 0]);
+        let dispersion = returns.reduce::<Asset, _>(MadKernel);
+        close(dispersion.to_vec(&mut backend), &[0.02, 0.01, 0.01, 0.01]);
+    }
+
+    #[test]
+    fn test_pairwise_distances() {
+        use super::Tensor;
+        use algebra::{
+            Axes, Correlation, DistanceMetric, Euclidean, Mahalanobis, TradingFloat,
+            assert_approx_eq, make_labels,
+        };
+
+        make_labels!(Day, Regime, Asset);
+        let mut backend = GenericBackend::new();
+        let tf = |xs: &[f64]| {
+            xs.iter()
+                .copied()
+                .map(TradingFloat::new)
+                .collect::<Vec<_>>()
+        };
+
+        // Per-asset returns on three days, and two regime centroids stored asset-major.
+        let days = Tensor::new(
+            tf(&[
+                0.02, 0.01, -0.01, //
+                -0.03, -0.02, 0.01, //
+                0.04, 0.03, -0.02,
+            ]),
+            [3, 3],
+        )
+        .into_named::<Axes!(Day, Asset)>();
+        let centroids = Tensor::new(
+            tf(&[
+                0.01, -0.01, //
+                0.01, -0.01, //
+                -0.01, 0.01,
+            ]),
+            [3, 2],
+        )
+        .into_named::<Axes!(Asset, Regime)>();
+
+        let euclid = days
+            .clone()
+            .cdist::<Day, Regime, Asset, _, _, _>(centroids.clone(), Euclidean)
+            .to_vec(&mut backend);
+        let norm = |x: [f64; 3]| x.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let expected = [
+            norm([0.01, 0.0, 0.0]),
+            norm([0.03, 0.02, -0.02]),
+            norm([-0.04, -0.03, 0.02]),
+            norm([-0.02, -0.01, 0.0]),
+            norm([0.03, 0.02, -0.01]),
+            norm([0.05, 0.04, -0.03]),
+        ];
+        for (g, e) in euclid.iter().zip(expected) {
+            assert!((g.to_f64() - e).abs() < 1e-12, "{g:?} vs {e}");
+        }
+
+        // Correlation ignores the size of the move: each day matches its regime exactly.
+        let corr = days
+            .clone()
+            .cdist::<Day, Regime, Asset, _, _, _>(centroids.clone(), Correlation)
+            .to_vec(&mut backend);
+        let nearest: Vec<usize> = corr
+            .chunks(2)
+            .map(|row| if row[0] < row[1] { 0 } else { 1 })
+            .collect();
+        assert_eq!(nearest, [0, 1, 0]);
+        assert!(corr[0].to_f64() < 0.1 && corr[2].to_f64() > 1.9);
+
+        // Rows are whitened once per operand; pairs then agree with a one-off comparison.
+        let metric = Mahalanobis::diagonal(&tf(&[1e-4, 4e-4, 1e-4]));
+        let whitened = days
+            .cdist::<Day, Regime, Asset, _, _, _>(centroids, metric.clone())
+            .to_vec(&mut backend);
+        let day_rows = [
+            [0.02, 0.01, -0.01],
+            [-0.03, -0.02, 0.01],
+            [0.04, 0.03, -0.02],
+        ];
+        let centroid_rows = [[0.01, 0.01, -0.01], [-0.01, -0.01, 0.01]];
+        for (i, day) in day_rows.iter().enumerate() {
+            for (j, centroid) in centroid_rows.iter().enumerate() {
+                let one_off = metric.between(&tf(day), &tf(centroid));
+                assert_approx_eq!(whitened[i * 2 + j], one_off);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_factories() {
+        use super::Tensor;
+        use algebra::{
+            Axes, Bernoulli, CounterRng, MeanKernel, Philox, StdKernel, TradingFloat, make_labels,
+        };
+
+        make_labels!(Path, Step);
+        let mut backend = GenericBackend::new();
+
+        // Same seed, same numbers: re-evaluating or rebuilding the expression is reproducible.
+        let shocks =
+            Tensor::<TradingFloat, _, _>::randn([4000, 2], 11).into_named::<Axes!(Path, Step)>();
+        let first = shocks.to_vec(&mut backend);
+        assert_eq!(shocks.to_vec(&mut backend), first);
+        assert_eq!(
+            Tensor::<TradingFloat, _, _>::randn([8000], 11).to_vec(&mut backend),
+            first
+        );
+        assert_ne!(
+            Tensor::<TradingFloat, _, _>::randn([8000], 12).to_vec(&mut backend),
+            first
+        );
+
+        // Moments per step, through the ordinary reductions.
+        let mean = shocks
+            .clone()
+            .reduce::<Path, _>(MeanKernel)
+            .to_vec(&mut backend);
+        let std = shocks
+            .reduce::<Path, _>(StdKernel { ddof: 1 })
+            .to_vec(&mut backend);
+        for (m, s) in mean.iter().zip(&std) {
+            assert!(m.to_f64().abs() < 0.05 && (s.to_f64() - 1.0).abs() < 0.05);
+        }
+        let uniform = Tensor::<TradingFloat, _, _>::rand([1000], 3).to_vec(&mut backend);
+        assert!(uniform.iter().all(|u| (0.0..1.0).contains(&u.to_f64())));
+
+        // `skip` continues the stream where an earlier batch stopped.
+        let coin = |n| Tensor::sample(Philox::new(5, 9), Bernoulli::new(0.5), [n]);
+        let all = coin(100).to_vec(&mut backend);
+        assert_eq!(coin(40).skip(60).to_vec(&mut backend), all[60..]);
+    }
+
+    #[test]
+    fn test_quasi_random_paths() {
+        use super::Tensor;
+        use algebra::{
+            Axes, BrownianBridge, Halton, MeanKernel, NormInvCdfKernel, Sobol, SumKernel,
+            TradingFloat, VarianceKernel, make_labels,
+        };
+
+        make_labels!(Path, Time);
+        let mut backend = GenericBackend::new();
+        let (paths, steps) = (4096, 16);
+        let t = TradingFloat::new;
+
+        // Scrambled Sobol -> normals -> bridge: one row of increments per path.
+        let increments = Tensor::<TradingFloat, _, _>::quasi_random(
+            Sobol::new(steps).unwrap().scrambled(7),
+            paths,
+        )
+        .into_named::<Axes!(Path, Time)>()
+        .map(NormInvCdfKernel)
+        .brownian_bridge::<Time>(BrownianBridge::uniform(steps, t(2.0)));
+        let terminal = increments.clone().reduce::<Time, _>(SumKernel);
+        let mean = terminal
+            .clone()
+            .reduce::<Path, _>(MeanKernel)
+            .to_vec(&mut backend);
+        let var = terminal
+            .reduce::<Path, _>(VarianceKernel::population())
+            .to_vec(&mut backend);
+        // W(T) ~ N(0, T), far inside the 1/√n noise of pseudo-random paths.
+        assert!(mean[0].to_f64().abs() < 1e-3, "{mean:?}");
+        assert!((var[0].to_f64() - 2.0).abs() < 1e-2, "{var:?}");
+
+        // Every increment has variance Δt.
+        let step_var = increments
+            .reduce::<Path, _>(VarianceKernel::population())
+            .to_vec(&mut backend);
+        assert!(step_var.iter().all(|v| (v.to_f64() - 0.125).abs() < 0.01));
+
+        // Batches continue the sequence.
+        let halton = |n| Tensor::<TradingFloat, _, _>::quasi_random(Halton::new(3), n);
+        let all = halton(10).to_vec(&mut backend);
+        assert_eq!(halton(4).skip(6).to_vec(&mut backend), all[18..]);
+    }
+
+    #[test]
+    fn test_monte_carlo_pricing() {
+        use super::{Base, Tensor};
+        use algebra::{
+            Axes, CallPayoff, CounterRng, GbmKernel, HestonKernel, HestonScheme, MeanKernel,
+            MertonKernel, Philox, Real, StandardNormals, StreamKernel, TradingFloat, make_labels,
+        };
+        use backend::Backend;
+
+        make_labels!(Path, Time);
+        let t = TradingFloat::new;
+        let (paths, steps) = (16_384, 8);
+        let (spot, strike, rate, horizon) = (100.0, 100.0, 0.05, 1.0);
+        let dt = t(horizon / steps as f64);
+        let discount = (-rate * horizon).exp();
+        let payoff = CallPayoff { strike: t(strike) };
+
+        // Discounted payoff at the last step, averaged with its antithetic twin.
+        fn price<K, const N: usize>(
+            kernel: K,
+            payoff: CallPayoff<TradingFloat>,
+            [paths, steps]: [usize; 2],
+            discount: f64,
+        ) -> f64
+        where
+            K: StreamKernel<[TradingFloat; N], Output = TradingFloat>,
+        {
+            let mut backend = GenericBackend::new();
+            let mut sum = 0.0;
+            for shocks in [StandardNormals::new(), StandardNormals::new().antithetic()] {
+                let mean = Tensor::sample(Philox::new(2024, 0), shocks, [paths, steps])
+                    .into_named::<Axes!(Path, Time)>()
+                    .stream::<Time, _>(kernel)
+                    .slice([0..paths, steps - 1..steps])
+                    .map(payoff)
+                    .reduce::<Path, _>(MeanKernel)
+                    .to_vec(&mut backend);
+                sum += mean[0].to_f64();
+            }
+            sum / 2.0 * discount
+        }
+
+        let black_scholes = |spot: f64, rate: f64, vol: f64| {
+            let sd = vol * horizon.sqrt();
+            let d1 = ((spot / strike).ln() + (rate + 0.5 * vol * vol) * horizon) / sd;
+            let n = |x: f64| t(x).norm_cdf().to_f64();
+            spot * n(d1) - strike * (-rate * horizon).exp() * n(d1 - sd)
+        };
+
+        let gbm = GbmKernel {
+            spot: t(spot),
+            rate: t(rate),
+            vol: t(0.2),
+            dt,
+        };
+        let bs = black_scholes(spot, rate, 0.2);
+        assert!(
+            (price(gbm, payoff, [paths, steps], discount) - bs).abs() < 0.2,
+            "{bs}"
+        );
+
+        // Merton's series: Poisson-weighted Black-Scholes prices conditional on n jumps.
+        let (intensity, jump_mean, jump_vol) = (1.0, -0.1, 0.15);
+        let merton = MertonKernel {
+            spot: t(spot),
+            rate: t(rate),
+            vol: t(0.2),
+            intensity: t(intensity),
+            jump_mean: t(jump_mean),
+            jump_vol: t(jump_vol),
+            dt,
+        };
+        let k = (jump_mean + 0.5 * jump_vol * jump_vol).exp() - 1.0;
+        let lambda = intensity * (1.0 + k) * horizon;
+        let (mut series, mut weight) = (0.0, (-lambda).exp());
+        for n in 0..40 {
+            let n = n as f64;
+            if n > 0.0 {
+                weight *= lambda / n;
+            }
+            let r_n = rate - intensity * k + n * (1.0 + k).ln() / horizon;
+            let vol_n = (0.04 + n * jump_vol * jump_vol / horizon).sqrt();
+            series += weight * black_scholes(spot, r_n, vol_n);
+        }
+        assert!(
+            (price(merton, payoff, [paths, steps], discount) - series).abs() < 0.25,
+            "{series}"
+        );
+
+        // Heston: both schemes agree, and the discounted spot is a martingale.
+        let heston = HestonKernel {
+            spot: t(spot),
+            var0: t(0.04),
+            rate: t(rate),
+            kappa: t(1.5),
+            theta: t(0.04),
+            xi: t(0.5),
+            rho: t(-0.7),
+            dt,
+            scheme: HestonScheme::FullTruncation,
+        };
+        let qe = HestonKernel {
+            scheme: HestonScheme::QuadraticExponential,
+            ..heston
+        };
+        let (ft_price, qe_price) = (
+            price(heston, payoff, [paths, steps], discount),
+            price(qe, payoff, [paths, steps], discount),
+        );
+        assert!(
+            (ft_price - qe_price).abs() < 0.3,
+            "{ft_price} vs {qe_price}"
+        );
+        let forward = price(qe, CallPayoff { strike: t(0.0) }, [paths, steps], discount);
+        assert!((forward - spot).abs() < 0.2, "{forward}");
+
+        // Pathwise delta: the tape pulls the terminal payoff back through each path to its spot.
+        let mut backend = GenericBackend::new();
+        let (_, tape) = Tensor::sample(Philox::new(7, 0), StandardNormals::new(), [paths, steps])
+            .into_named::<Axes!(Path, Time)>()
+            .stream::<Time, _>(gbm)
+            .map(payoff)
+            .forward(&mut backend);
+        let seed: Vec<_> = (0..paths * steps)
+            .map(|i| t(if i % steps == steps - 1 { 1.0 } else { 0.0 }))
+            .collect();
+        let seed = Base::new(backend.pure(&seed), [paths, steps]);
+        let grad = tape.backward(&mut backend, seed);
+        assert_eq!(grad.shape, [paths, 1]);
+        let delta = backend
+            .to_host(&grad.storage)
+            .iter()
+            .map(|g| g.to_f64())
+            .sum::<f64>()
+            / paths as f64
+            * discount;
+        let d1 = ((spot / strike).ln() + (rate + 0.02) * horizon) / 0.2;
+        assert!((delta - t(d1).norm_cdf().to_f64()).abs() < 0.015, "{delta}");
+    }
+
+    #[test]
+    fn test_fused_elementwise() {
+        use super::Tensor;
+        use algebra::{
+            Axes, BinaryKernel, ChainedKernel, MulKernel, ScaleKernel, UnaryKernel, make_labels,
+        };
+
+        make_labels!(Row, Col);
+        let mut backend = GenericBackend::new();
+        let x =
+            Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]).into_named::<Axes!(Row, Col)>();
+
+        // Maps over a strided view: the fused pass writes a dense result in view order.
+        let t = x.clone().align::<Axes!(Col, Row)>().scale(2.0).scale(0.5) + 1.0;
+        assert_eq!(t.to_vec(&mut backend), [2.0, 5.0, 3.0, 6.0, 4.0, 7.0]);
+        let s = x.clone().slice([0..2, 1..3]).scale(10.0);
+        assert_eq!(s.to_vec(&mut backend), [20.0, 30.0, 50.0, 60.0]);
+
+        // Unary after binary runs inside the binary pass.
+        let y = (x.clone() * x.clone()).scale(0.5).scale(4.0);
+        assert_eq!(y.to_vec(&mut backend), [2.0, 8.0, 18.0, 32.0, 50.0, 72.0]);
+
+        let half = ScaleKernel { factor: 0.5 };
+        let chained = ChainedKernel { k1: half, k2: half };
+        assert_eq!(chained.apply(8.0), 2.0);
+        let fused = ChainedKernel {
+            k1: MulKernel,
+            k2: half,
+        };
+        assert_eq!(BinaryKernel::apply(&fused, 3.0, 4.0), 6.0);
+    }
+
+    #[test]
+    fn test_activation_backward() {
+        use super::{Base, Tensor};
+        use algebra::{
+            HardStepKernel, SigmoidSurrogate, SiluKernel, TradingFloat, assert_approx_eq,
+        };
+        use backend::Backend;
+
+        let mut backend = GenericBackend::new();
+        let tf = TradingFloat::new;
+        let x = Tensor::new([-2.0, -0.5, 0.5, 3.0].map(tf).to_vec(), [4]);
+        let seed = |backend: &mut GenericBackend| Base::new(backend.pure(&[tf(1.0); 4]), [4]);
+
+        // The gradient of a map is the kernel's derivative times the incoming gradient.
+        let (y, tape) = x.clone().map(SiluKernel).forward(&mut backend);
+        let seed_grad = seed(&mut backend);
+        let grad = tape.backward(&mut backend, seed_grad);
+        for (g, x) in backend
+            .to_host(&grad.storage)
+            .into_iter()
+            .zip([-2.0, -0.5, 0.5, 3.0])
+        {
+            let s = 1.0 / (1.0 + f64::exp(-x));
+            assert_approx_eq!(g, tf(s * (1.0 + x * (1.0 - s))));
+        }
+        assert_approx_eq!(
+            backend.to_host(&y.storage)[3],
+            tf(3.0 / (1.0 + f64::exp(-3.0)))
+        );
+
+        // A hard step trains through its surrogate.
+        let step = HardStepKernel {
+            surrogate: SigmoidSurrogate { beta: tf(1.0) },
+        };
+        let (y, tape) = x.map(step).forward(&mut backend);
+        assert_eq!(backend.to_host(&y.storage), [0.0, 0.0, 1.0, 1.0].map(tf));
+        let seed_grad = seed(&mut backend);
+        let grad = tape.backward(&mut backend, seed_grad);
+        let grad = backend.to_host(&grad.storage);
+        assert!(grad.iter().all(|&g| g > tf(0.0)));
+        assert_approx_eq!(grad[1], grad[2]);
+    }
+
+    #[test]
+    fn test_all_close() {
+        use algebra::{ApproxEq, Tolerance, TradingFloat, assert_approx_eq};
+
+        let mut backend = GenericBackend::new();
+        let a = tensor![[0.1, 0.2], [0.3, 1e6]];
+        let b = tensor![[0.1, 0.2], [0.1 + 0.2, 1e6 + 1e-4]];
+
+        assert_eq!(
+            a.all_close(&b, Tolerance::Ulps(4), &mut backend),
+            Err([1, 1])
+        );
+        assert_eq!(
+            a.all_close(&b, Tolerance::Absolute(1e-12), &mut backend),
+            Err([1, 1])
+        );
+        assert_all_close!(&mut backend, a, b, Tolerance::Relative(1e-9));
+
+        assert_approx_eq!(TradingFloat::new(0.1 + 0.2), TradingFloat::new(0.3));
+        assert_approx_eq!(1.0f32, 1.0 + 1e-6, Tolerance::Absolute(1e-5));
+        assert!(!f64::NAN.approx_eq(&f64::NAN, Tolerance::Absolute(1.0)));
+    }
+
+    #[test]
+    fn test_checked_errors_propagate() {
+        use crate::Tensor;
+        use algebra::{ArithmeticError, Checked, TradingFloat};
+
+        let mut backend = GenericBackend::new();
+        let checked = |xs: [f64; 3]| xs.map(|x| Checked::new(TradingFloat::new(x)));
+        let a = Tensor::from(checked([1.0, 2.0, 3.0]));
+        let b = Tensor::from(checked([1.0, 0.0, 3.0]));
+        let c = Tensor::from(checked([1.0, 1.0, 1.0]));
+
+        let result = ((a / b) + c).to_vec(&mut backend);
+
+        assert_eq!(result[0].into_result(), Ok(TradingFloat::new(2.0)));
+        assert_eq!(
+            result[1].into_result(),
+            Err(ArithmeticError::DivisionByZero)
+        );
+        assert_eq!(result[2].into_result(), Ok(TradingFloat::new(2.0)));
+        assert_eq!(
+            result
+                .into_iter()
+                .sum::<Checked<TradingFloat>>()
+                .into_result(),
+            Err(ArithmeticError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_contract_semirings() {
+        use crate::Tensor;
+        use algebra::{Axes, MaxPlus, MinPlus, SumKernel, TradingFloat, make_labels};
+        use backend::Backend;
+
+        make_labels!(Batch, Src, Mid, Dst);
+        let mut backend = GenericBackend::new();
+
+        // Plain matmul, with the left operand stored transposed.
+        let a = tensor![[1.0, 2.0], [3.0, 4.0]].into_named::<Axes!(Src, Mid)>();
+        let a_t = tensor![[1.0, 3.0], [2.0, 4.0]].into_named::<Axes!(Mid, Src)>();
+        let b = tensor![[5.0, 6.0], [7.0, 8.0]].into_named::<Axes!(Mid, Dst)>();
+        let expected = tensor![[19.0, 22.0], [43.0, 50.0]].into_named::<Axes!(Src, Dst)>();
+        assert_all_close!(
+            &mut backend,
+            a.contract::<Axes!(Mid), _, _>(b.clone()),
+            expected
+        );
+        assert_all_close!(&mut backend, a_t.contract::<Axes!(Mid), _, _>(b), expected);
+
+        // Batch axes are kept once and matched element-wise.
+        let x = Tensor::new(
+            [1.0, 2.0, 3.0, 4.0].map(TradingFloat::new).to_vec(),
+            [2, 1, 2],
+        )
+        .into_named::<Axes!(Batch, Src, Mid)>();
+        let y = Tensor::new([1.0, 1.0, 2.0, 2.0].map(TradingFloat::new).to_vec(), [2, 2])
+            .into_named::<Axes!(Batch, Mid)>();
+        let xy = x.contract::<Axes!(Mid), _, _>(y).to_vec(&mut backend);
+        assert_eq!(xy, [3.0, 14.0].map(TradingFloat::new));
+
+        // Min-plus: one relaxation step gives the cheapest path with at most two hops.
+        let inf = MinPlus::INFINITY;
+        let w = |c: i64| MinPlus::new(c);
+        let edges = vec![w(0), w(4), w(10), inf, w(0), w(3), inf, inf, w(0)];
+        let hop = Tensor::new(edges.clone(), [3, 3]).into_named::<Axes!(Src, Mid)>();
+        let next = Tensor::new(edges, [3, 3]).into_named::<Axes!(Mid, Dst)>();
+        let paths = hop.contract::<Axes!(Mid), _, _>(next).to_vec(&mut backend);
+        assert_eq!(
+            paths,
+            vec![w(0), w(4), w(7), inf, w(0), w(3), inf, inf, w(0)]
+        );
+
+        // Max-plus: a Viterbi step over log-scores, and a max via the plain sum reduction.
+        let s = |x: f64| MaxPlus::new(TradingFloat::new(x));
+        let delta = Tensor::new(vec![s(-1.0), s(-2.0)], [2]).into_named::<Axes!(Src)>();
+        let trans = Tensor::new(vec![s(-0.5), s(-3.0), s(-1.0), s(-0.1)], [2, 2])
+            .into_named::<Axes!(Src, Dst)>();
+        let best = delta
+            .contract::<Axes!(Src), _, _>(trans)
+            .to_vec(&mut backend);
+        assert_eq!(best, vec![s(-1.5), s(-2.1)]);
+
+        let input = backend.pure(&best);
+        let max = backend.reduce(&input, SumKernel);
+        assert_eq!(backend.to_host(&max), vec![s(-1.5)]);
+    }
+
+    #[test]
+    fn test_log_space_inference() {
+        use crate::Tensor;
+        use algebra::{
+            ApproxEq, Axes, LogSpace, Tolerance, TradingFloat, assert_approx_eq, make_labels,
+        };
+
+        make_labels!(State, Next);
+        let mut backend = GenericBackend::new();
+        let f = TradingFloat::new;
+        let log = |ps: &[f64]| {
+            ps.iter()
+                .map(|&p| LogSpace::from_prob(f(p)))
+                .collect::<Vec<_>>()
+        };
+
+        // HMM forward pass: alpha' = (alpha . T) * e(obs), with emissions scaled by 1e-200
+        // so the probability-space likelihood (~1e-600) underflows f64.
+        let trans = Tensor::new(log(&[0.7, 0.3, 0.4, 0.6]), [2, 2]);
+        let emit = [[0.9, 0.2], [0.1, 0.8]];
+        let scale = 1e-200;
+        let obs = [0, 1, 1];
+
+        let mut alpha = log(&[0.5 * emit[obs[0]][0] * scale, 0.5 * emit[obs[0]][1] * scale]);
+        let mut alpha_p = [0.5 * emit[obs[0]][0], 0.5 * emit[obs[0]][1]];
+        for &o in &obs[1..] {
+            let step = Tensor::new(alpha, [2])
+                .into_named::<Axes!(State)>()
+                .contract::<Axes!(State), _, _>(trans.clone().into_named::<Axes!(State, Next)>());
+            let e = Tensor::new(log(&[emit[o][0] * scale, emit[o][1] * scale]), [2])
+                .into_named::<Axes!(Next)>();
+            alpha = (step * e).to_vec(&mut backend);
+
+            alpha_p = [
+                (alpha_p[0] * 0.7 + alpha_p[1] * 0.4) * emit[o][0],
+                (alpha_p[0] * 0.3 + alpha_p[1] * 0.6) * emit[o][1],
+            ];
+        }
+
+        let likelihood: LogSpace<TradingFloat> = alpha.into_iter().sum();
+        let expected = (alpha_p[0] + alpha_p[1]).ln() + 3.0 * scale.ln();
+        assert_eq!(likelihood.to_prob(), f(0.0));
+        assert_approx_eq!(
+            likelihood.ln().unwrap(),
+            f(expected),
+            Tolerance::Relative(1e-12)
+        );
+
+        // An absolute tolerance on the logs is a bound on the probability ratio.
+        let p = LogSpace::from_prob(f(1e-300));
+        assert_approx_eq!(
+            p,
+            LogSpace::from_prob(f(1.0099e-300)),
+            Tolerance::Absolute(0.01)
+        );
+        assert!(!p.abs_diff_eq(&LogSpace::from_prob(f(1.0101e-300)), 0.01));
+
+        // Scans run along the last axis: a running log-partition per row.
+        let rows = Tensor::new(log(&[0.1, 0.2, 0.3, 0.5, 0.0, 0.5]), [2, 3]);
+        let cumulative = rows.cumsum().to_vec(&mut backend);
+        let expected = log(&[0.1, 0.3, 0.6, 0.5, 0.5, 1.0]);
+        for (got, want) in cumulative.iter().zip(&expected) {
+            assert_approx_eq!(*got, *want, Tolerance::Absolute(1e-12));
+        }
+
+        let prices = tensor![[1.0, 2.0, 3.0], [2.0, 2.0, 0.5]];
+        assert_eq!(
+            prices.cumprod().to_vec(&mut backend),
+            [1.0, 2.0, 6.0, 2.0, 4.0, 2.0].map(f)
+        );
+    }
 }
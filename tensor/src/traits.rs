@@ -1,11 +1,23 @@
-use super::Base;
-use algebra::{Data, Real, Semiring};
+use super::{Base, Lower, PackDense};
+use algebra::{Data, Real, Semiring, UnaryKernel};
 use backend::Backend;
 
 pub trait Evaluator<B: Backend, const RANK: usize> {
     type Data: Data;
 
     fn eval(&self, backend: &mut B) -> Base<B::Storage<Self::Data>, Self::Data, RANK>;
+
+    // `eval` followed by an element-wise `kernel`. Expressions that already end in an
+    // element-wise pass override this to fold `kernel` into it instead of adding a pass.
+    fn eval_map<K: UnaryKernel<Self::Data>>(
+        &self,
+        backend: &mut B,
+        kernel: K,
+    ) -> Base<B::Storage<K::Output>, K::Output, RANK> {
+        let view = self.eval(backend);
+        let input = Lower::<PackDense, B>::lower(&view, backend);
+        Base::new(backend.unary(&input, kernel), view.shape)
+    }
 }
 
 pub trait Pullback<B: Backend, const R: usize> {
@@ -27,6 +39,7 @@ where
 {
     type Adjoint: Pullback<B, R, Primal = Self::Data, Cotangent = Self::Data>;
 
+    #[allow(clippy::type_complexity)]
     fn forward(
         &self,
         backend: &mut B,